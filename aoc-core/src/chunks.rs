@@ -0,0 +1,300 @@
+use std::fs::File;
+use std::io::{self, Cursor, Read, Seek, SeekFrom};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::panic;
+use std::thread;
+
+use tracing::{info_span, Span};
+
+// Number of bytes read at once while searching for line boundaries
+const BLOCK_SIZE: usize = 4096;
+
+/// Byte range of whole lines of an input, together with the byte range including neighbouring context lines
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Chunk {
+    /// The lines belonging to this chunk, each line belongs to exactly one chunk
+    pub lines: Range<usize>,
+    /// The lines of the chunk extended by the overlapping lines before and after it
+    pub context: Range<usize>,
+}
+
+impl Chunk {
+    /// The lines of the chunk and its context within the input
+    pub fn lines_in<'a>(&self, input: &'a str) -> ChunkLines<'a> {
+        ChunkLines::new(&input[self.context.clone()], self)
+    }
+}
+
+/// Split the input into at most `count` chunks of whole lines of roughly equal size,
+/// each with up to `overlap` neighbouring lines before and after it as context
+pub fn split(input: &str, count: usize, overlap: usize) -> Vec<Chunk> {
+    split_reader(&mut Cursor::new(input.as_bytes()), input.len(), count, overlap)
+        .expect("Reading from memory cannot fail")
+}
+
+/// Reads chunks of a file in parallel, where every thread seeks to its own chunk
+#[derive(Clone, Debug)]
+pub struct ChunkReader {
+    path: PathBuf,
+    len: usize,
+}
+
+impl ChunkReader {
+    /// Prepare reading chunks of the file at the given path
+    pub fn open(path: impl AsRef<Path>) -> io::Result<ChunkReader> {
+        let path = path.as_ref().to_path_buf();
+        let len = path.metadata()?.len() as usize;
+        Ok(ChunkReader { path, len })
+    }
+
+    /// Split the file into at most `count` chunks of whole lines, see [split]
+    pub fn split(&self, count: usize, overlap: usize) -> io::Result<Vec<Chunk>> {
+        split_reader(&mut File::open(&self.path)?, self.len, count, overlap)
+    }
+
+    /// Read a chunk including its context from the file
+    pub fn read(&self, chunk: &Chunk) -> io::Result<String> {
+        let mut file = File::open(&self.path)?;
+        file.seek(SeekFrom::Start(chunk.context.start as u64))?;
+
+        let mut buffer = vec![0; chunk.context.len()];
+        file.read_exact(&mut buffer)?;
+        String::from_utf8(buffer).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// Read the file in one chunk per thread with `overlap` lines of context, map every chunk on its own thread and reduce the results
+    pub fn map_reduce<T, M, R>(&self, threads: usize, overlap: usize, identity: T, map: M, reduce: R) -> io::Result<T>
+    where
+        T: Send,
+        M: Fn(&ChunkLines) -> T + Sync,
+        R: Fn(T, T) -> T,
+    {
+        let chunks = self.split(threads, overlap)?;
+
+        let parent = Span::current();
+        let results: Vec<io::Result<T>> = thread::scope(|scope| {
+            let handles: Vec<_> = chunks.iter()
+                .map(|chunk| {
+                    let map = &map;
+                    let worker = info_span!(parent: &parent, "worker", strategy = "chunk-reader");
+                    scope.spawn(move || worker.in_scope(|| {
+                        let text = self.read(chunk)?;
+                        Ok(map(&ChunkLines::new(&text, chunk)))
+                    }))
+                })
+                .collect();
+
+            handles.into_iter()
+                .map(|handle| handle.join().unwrap_or_else(|payload| panic::resume_unwind(payload)))
+                .collect()
+        });
+
+        info_span!("reduce").in_scope(|| results.into_iter().try_fold(identity, |result, other| Ok(reduce(result, other?))))
+    }
+}
+
+/// The lines of a chunk, with access to the neighbouring context lines
+#[derive(Clone, Debug)]
+pub struct ChunkLines<'a> {
+    lines: Vec<&'a str>,
+    // Indices of the lines belonging to the chunk itself
+    own: Range<usize>,
+}
+
+impl<'a> ChunkLines<'a> {
+    /// Split the text covering the context of a chunk into its lines
+    pub fn new(text: &'a str, chunk: &Chunk) -> ChunkLines<'a> {
+        let start = chunk.lines.start - chunk.context.start;
+        let end = chunk.lines.end - chunk.context.start;
+
+        // The chunk starts at a line boundary, so every line before it is a context line
+        let before = text[..start].lines().count();
+        let own = before..before + text[start..end].lines().count();
+
+        ChunkLines { lines: text.lines().collect(), own }
+    }
+
+    /// Iterate over the lines of the chunk, excluding the context lines
+    pub fn iter(&self) -> impl Iterator<Item = Line<'_, 'a>> {
+        self.own.clone().map(|index| Line { lines: &self.lines, index })
+    }
+
+    /// The lines of the chunk together with its context lines
+    pub fn context(&self) -> &[&'a str] {
+        &self.lines
+    }
+
+    /// The indices of the lines of the chunk itself within its [context](ChunkLines::context)
+    pub fn own(&self) -> Range<usize> {
+        self.own.clone()
+    }
+}
+
+/// A line of a chunk that can look at its neighbours
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'c, 'a> {
+    lines: &'c [&'a str],
+    index: usize,
+}
+
+impl<'a> Line<'_, 'a> {
+    /// The text of the line
+    pub fn text(&self) -> &'a str {
+        self.lines[self.index]
+    }
+
+    /// The line at the given offset from this line, if it is within the chunk or its context
+    pub fn neighbour(&self, offset: isize) -> Option<&'a str> {
+        self.index.checked_add_signed(offset)
+            .and_then(|index| self.lines.get(index))
+            .copied()
+    }
+}
+
+// Split a seekable input of the given length into chunks, using seeks to find the line boundaries
+fn split_reader<R: Read + Seek>(reader: &mut R, len: usize, count: usize, overlap: usize) -> io::Result<Vec<Chunk>> {
+    // Move every evenly spaced target offset forward to the start of the next line
+    let mut boundaries = vec![0];
+    for i in 1..count.max(1) {
+        let target = len * i / count;
+        let boundary = next_line_start(reader, len, target.saturating_sub(1))?;
+        if boundary > *boundaries.last().unwrap() {
+            boundaries.push(boundary);
+        }
+    }
+    if len > *boundaries.last().unwrap() {
+        boundaries.push(len);
+    }
+
+    // Extend every chunk with the overlapping lines on either side
+    boundaries.windows(2)
+        .map(|range| {
+            let (start, end) = (range[0], range[1]);
+            let mut context = start..end;
+            for _ in 0..overlap {
+                context.start = previous_line_start(reader, context.start)?;
+                context.end = next_line_start(reader, len, context.end)?;
+            }
+            Ok(Chunk { lines: start..end, context })
+        })
+        .collect()
+}
+
+// Find the start of the first line after the given position, or the end of the input
+fn next_line_start<R: Read + Seek>(reader: &mut R, len: usize, position: usize) -> io::Result<usize> {
+    let mut block = [0; BLOCK_SIZE];
+    let mut offset = position;
+    reader.seek(SeekFrom::Start(offset as u64))?;
+
+    while offset < len {
+        let read = reader.read(&mut block)?;
+        if read == 0 {
+            break;
+        }
+        if let Some(newline) = block[..read].iter().position(|&byte| byte == b'\n') {
+            return Ok(offset + newline + 1);
+        }
+        offset += read;
+    }
+
+    Ok(len)
+}
+
+// Find the start of the line before the line starting at the given position, or the start of the input
+fn previous_line_start<R: Read + Seek>(reader: &mut R, position: usize) -> io::Result<usize> {
+    let mut block = [0; BLOCK_SIZE];
+
+    // Skip the newline ending the previous line
+    let mut end = position.saturating_sub(1);
+    while end > 0 {
+        let start = end.saturating_sub(BLOCK_SIZE);
+        reader.seek(SeekFrom::Start(start as u64))?;
+        reader.read_exact(&mut block[..end - start])?;
+        if let Some(newline) = block[..end - start].iter().rposition(|&byte| byte == b'\n') {
+            return Ok(start + newline + 1);
+        }
+        end = start;
+    }
+
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use super::*;
+
+    // Lines of varying length, including empty lines and a final line without newline
+    const INPUT: &str = "a\nbbbbbbbb\n\ncc\nddddd\ne\n\nffffffffffff\ng";
+
+    fn owned_lines(input: &str, chunks: &[Chunk]) -> Vec<String> {
+        chunks.iter()
+            .flat_map(|chunk| chunk.lines_in(input).iter().map(|line| line.text().to_string()).collect::<Vec<_>>())
+            .collect()
+    }
+
+    #[test]
+    fn chunks_cover_every_line_once() {
+        for count in 1..=20 {
+            let chunks = split(INPUT, count, 0);
+            assert!(chunks.len() <= count);
+            assert_eq!(owned_lines(INPUT, &chunks), INPUT.lines().collect::<Vec<_>>(), "{} chunks", count);
+        }
+    }
+
+    #[test]
+    fn chunks_are_line_aligned() {
+        for count in 1..=20 {
+            for chunk in split(INPUT, count, 2) {
+                for boundary in [chunk.lines.start, chunk.context.start] {
+                    assert!(boundary == 0 || INPUT.as_bytes()[boundary - 1] == b'\n');
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn overlap_includes_neighbours() {
+        let lines: Vec<&str> = INPUT.lines().collect();
+        for count in 1..=20 {
+            let chunks = split(INPUT, count, 1);
+            let mut index = 0;
+            for chunk in &chunks {
+                for line in chunk.lines_in(INPUT).iter() {
+                    assert_eq!(line.text(), lines[index]);
+                    assert_eq!(line.neighbour(-1), index.checked_sub(1).map(|i| lines[i]));
+                    assert_eq!(line.neighbour(1), lines.get(index + 1).copied());
+                    index += 1;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn empty_input() {
+        assert!(split("", 4, 1).is_empty());
+    }
+
+    #[test]
+    fn file_matches_memory() {
+        let path = env::temp_dir().join(format!("aoc-core-chunks-{}", std::process::id()));
+        let input = INPUT.repeat(BLOCK_SIZE / 8);
+        fs::write(&path, &input).unwrap();
+
+        let reader = ChunkReader::open(&path).unwrap();
+        for count in [1, 3, 7] {
+            let chunks = reader.split(count, 1).unwrap();
+            assert_eq!(chunks, split(&input, count, 1));
+            for chunk in &chunks {
+                assert_eq!(reader.read(chunk).unwrap(), &input[chunk.context.clone()]);
+            }
+        }
+
+        let count = reader.map_reduce(3, 1, 0, |lines| lines.iter().count(), |a, b| a + b).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(count, input.lines().count());
+    }
+}
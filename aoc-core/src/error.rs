@@ -0,0 +1,166 @@
+use std::error::Error;
+use std::fmt;
+use std::process;
+
+/// What kind of problem an input has
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// Something else was expected at the line and column of the error
+    #[default]
+    Unexpected,
+    /// The answer does not fit in its type, which no single position in the input is to blame for
+    Overflow,
+    /// The search for the answer gave up before it finished, which no single position in the input is to blame for either
+    Limit,
+}
+
+/// Error in the puzzle input, pointing at the line and column where something else was expected,
+/// or reporting that the answer to the input overflowed or could not be searched for
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub kind: ErrorKind,
+    /// Line number starting at 1, 0 for an overflow or a limit
+    pub line: usize,
+    /// Column in characters starting at 1
+    pub column: usize,
+    /// Description of the token that was expected
+    pub expected: String,
+    /// The offending line
+    pub excerpt: String,
+}
+
+impl ParseError {
+    /// Create an error at a byte offset of a line, the line number is set by [ParseError::at_line]
+    pub fn new(line: &str, offset: usize, expected: impl Into<String>) -> ParseError {
+        let offset = offset.min(line.len());
+        ParseError {
+            kind: ErrorKind::Unexpected,
+            line: 0,
+            column: line.get(..offset).map_or(offset, |before| before.chars().count()) + 1,
+            expected: expected.into(),
+            excerpt: line.to_string(),
+        }
+    }
+
+    /// Create an error for input that ends while something else was expected, after the given number of lines
+    pub fn end_of_input(lines: usize, expected: impl Into<String>) -> ParseError {
+        ParseError::new("", 0, expected).at_line(lines)
+    }
+
+    /// Create an error for an answer that does not fit in its type, where the type that was expected is described
+    pub fn overflow(expected: impl Into<String>) -> ParseError {
+        ParseError { kind: ErrorKind::Overflow, ..ParseError::new("", 0, expected) }
+    }
+
+    /// Create an error for a search that gave up on the input, where the limit it stayed within is described
+    pub fn limit(expected: impl Into<String>) -> ParseError {
+        ParseError { kind: ErrorKind::Limit, ..ParseError::new("", 0, expected) }
+    }
+
+    /// Set the line number from the index of the line in the input
+    pub fn at_line(mut self, index: usize) -> ParseError {
+        self.line = index + 1;
+        self
+    }
+
+    /// Move the error down by a number of lines, for an error in a part of the input that starts after these lines
+    pub fn after_lines(mut self, lines: usize) -> ParseError {
+        self.line += lines;
+        self
+    }
+
+    /// Describe what was found at the position of the error
+    pub fn found(&self) -> String {
+        match self.excerpt.chars().nth(self.column - 1) {
+            Some(c) => format!("{:?}", c),
+            None if self.excerpt.is_empty() => "an empty line".to_string(),
+            None => "the end of the line".to_string(),
+        }
+    }
+
+    /// Render the error together with the offending line and a marker under the column, if it has a position
+    pub fn diagnostic(&self) -> String {
+        if self.kind != ErrorKind::Unexpected {
+            return self.to_string();
+        }
+
+        let number = self.line.to_string();
+        let padding = " ".repeat(number.len());
+        format!(
+            "{}\n{} |\n{} | {}\n{} | {}^",
+            self, padding, number, self.excerpt, padding, " ".repeat(self.column - 1)
+        )
+    }
+
+    /// Print the diagnostic and exit the process
+    pub fn exit(&self) -> ! {
+        eprintln!("error: {}", self.diagnostic());
+        process::exit(1);
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ErrorKind::Unexpected => write!(f, "line {}, column {}: expected {}, found {}", self.line, self.column, self.expected, self.found()),
+            ErrorKind::Overflow => write!(f, "the answer overflowed, expected {}", self.expected),
+            ErrorKind::Limit => write!(f, "the search for the answer gave up, expected {}", self.expected),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Keep the error on the earliest line when combining results, so every execution strategy reports the same error
+pub fn first_error<T>(a: Result<T, ParseError>, b: Result<T, ParseError>, combine: impl FnOnce(T, T) -> T) -> Result<T, ParseError> {
+    match (a, b) {
+        (Ok(a), Ok(b)) => Ok(combine(a, b)),
+        (Err(a), Err(b)) => Err(if b.line < a.line { b } else { a }),
+        (Err(err), _) | (_, Err(err)) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagnostic() {
+        let err = ParseError::new("32T3X 765", 4, "a card").at_line(2);
+        assert_eq!(err.to_string(), "line 3, column 5: expected a card, found 'X'");
+        assert_eq!(err.diagnostic(), "\
+line 3, column 5: expected a card, found 'X'
+  |
+3 | 32T3X 765
+  |     ^");
+    }
+
+    #[test]
+    fn found_end() {
+        assert_eq!(ParseError::new("Game 1", 6, "`:`").found(), "the end of the line");
+        assert_eq!(ParseError::end_of_input(4, "a line with seeds").to_string(), "line 5, column 1: expected a line with seeds, found an empty line");
+    }
+
+    #[test]
+    fn overflow() {
+        let err = ParseError::overflow("a total that fits in u128");
+        assert_eq!(err.to_string(), "the answer overflowed, expected a total that fits in u128");
+        assert_eq!(err.diagnostic(), err.to_string());
+    }
+
+    #[test]
+    fn limit() {
+        let err = ParseError::limit("at most 10 combinations");
+        assert_eq!(err.to_string(), "the search for the answer gave up, expected at most 10 combinations");
+        assert_eq!(err.diagnostic(), err.to_string());
+    }
+
+    #[test]
+    fn earliest_error_wins() {
+        let early = Err(ParseError::new("a", 0, "b").at_line(1));
+        let late = Err(ParseError::new("c", 0, "d").at_line(7));
+        assert_eq!(first_error(late.clone(), early.clone(), |a: u32, b| a + b), early);
+        assert_eq!(first_error(early.clone(), Ok(1), |a, b| a + b), early);
+        assert_eq!(first_error(Ok(1), Ok(2), |a, b| a + b), Ok(3));
+    }
+}
@@ -0,0 +1,402 @@
+use std::fmt;
+use std::io::{self, BufRead};
+use std::iter;
+use std::panic;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, ScopedJoinHandle};
+
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use tracing::{info_span, Span};
+
+use crate::error::{first_error, ParseError};
+use crate::solver::LineSolver;
+
+/// How work is distributed over threads
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    /// Process all items one after another on the calling thread
+    Sequential,
+    /// Let rayon distribute the items over its thread pool
+    Rayon,
+    /// Split the items into one contiguous chunk per thread
+    Chunked,
+    /// Let every thread take the next item from an iterator shared behind a mutex
+    Shared,
+}
+
+impl Strategy {
+    /// All available strategies
+    pub const ALL: [Strategy; 4] = [Strategy::Sequential, Strategy::Rayon, Strategy::Chunked, Strategy::Shared];
+}
+
+impl FromStr for Strategy {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Strategy, String> {
+        Strategy::ALL.into_iter()
+            .find(|strategy| strategy.to_string() == name)
+            .ok_or_else(|| format!("Unknown strategy {}, expected one of sequential, rayon, chunked or shared", name))
+    }
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Strategy::Sequential => "sequential",
+            Strategy::Rayon => "rayon",
+            Strategy::Chunked => "chunked",
+            Strategy::Shared => "shared",
+        })
+    }
+}
+
+/// Runs independent pieces of work, such as the lines of an input, under a strategy with a number of threads
+#[derive(Clone, Debug)]
+pub struct Executor {
+    strategy: Strategy,
+    threads: usize,
+    // Dedicated rayon pool when a specific number of threads was requested
+    pool: Option<Arc<ThreadPool>>,
+}
+
+impl Executor {
+    /// Create an executor using the given number of threads, or one thread per cpu if not given
+    pub fn new(strategy: Strategy, threads: Option<usize>) -> Executor {
+        let pool = match (strategy, threads) {
+            (Strategy::Rayon, Some(threads)) => Some(Arc::new(
+                ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .expect("Could not build rayon thread pool"),
+            )),
+            _ => None,
+        };
+
+        let threads = match strategy {
+            Strategy::Sequential => 1,
+            _ => threads.unwrap_or_else(num_cpus::get).max(1),
+        };
+
+        Executor { strategy, threads, pool }
+    }
+
+    /// Create an executor that processes everything on the calling thread
+    pub fn sequential() -> Executor {
+        Executor::new(Strategy::Sequential, None)
+    }
+
+    pub fn strategy(&self) -> Strategy {
+        self.strategy
+    }
+
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// Map every item together with its index and reduce the results
+    ///
+    /// Items may be reduced in any order, so `reduce` should be associative and commutative with `identity` as identity.
+    pub fn map_reduce<I, T, M, R>(&self, items: &[I], identity: impl Fn() -> T + Sync, map: M, reduce: R) -> T
+    where
+        I: Sync,
+        T: Send,
+        M: Fn(usize, &I) -> T + Sync,
+        R: Fn(T, T) -> T + Sync,
+    {
+        // Fold a run of items starting at the given index on the current thread
+        let fold = |start: usize, items: &[I]| items.iter()
+            .enumerate()
+            .fold(identity(), |result, (i, item)| reduce(result, map(start + i, item)));
+
+        // Workers on other threads record their spans under the span of the caller
+        let parent = Span::current();
+        match self.strategy {
+            Strategy::Sequential => fold(0, items),
+            Strategy::Rayon => self.install(|| items.par_iter()
+                .enumerate()
+                .map_init(|| self.worker(&parent).entered(), |_, (i, item)| map(i, item))
+                .reduce(&identity, &reduce)),
+            Strategy::Chunked => {
+                let chunk_length = self.chunk_length(items.len());
+                let results: Vec<T> = thread::scope(|scope| {
+                    let handles: Vec<_> = items.chunks(chunk_length)
+                        .enumerate()
+                        .map(|(chunk, items)| {
+                            let worker = self.worker(&parent);
+                            scope.spawn(move || worker.in_scope(|| fold(chunk * chunk_length, items)))
+                        })
+                        .collect();
+                    join_all(handles).collect()
+                });
+                info_span!("reduce").in_scope(|| results.into_iter().fold(identity(), &reduce))
+            }
+            Strategy::Shared => self.map_reduce_shared(items.iter().enumerate(), identity, map, reduce),
+        }
+    }
+
+    /// Map every item together with its index and collect the results in the order of the items
+    pub fn map_collect<I, T, M>(&self, items: &[I], map: M) -> Vec<T>
+    where
+        I: Sync,
+        T: Send,
+        M: Fn(usize, &I) -> T + Sync,
+    {
+        let parent = Span::current();
+        match self.strategy {
+            Strategy::Sequential => items.iter()
+                .enumerate()
+                .map(|(i, item)| map(i, item))
+                .collect(),
+            Strategy::Rayon => self.install(|| items.par_iter()
+                .enumerate()
+                .map_init(|| self.worker(&parent).entered(), |_, (i, item)| map(i, item))
+                .collect()),
+            Strategy::Chunked => {
+                let chunk_length = self.chunk_length(items.len());
+                let map = &map;
+                thread::scope(|scope| {
+                    let handles: Vec<_> = items.chunks(chunk_length)
+                        .enumerate()
+                        .map(|(chunk, items)| {
+                            let worker = self.worker(&parent);
+                            scope.spawn(move || worker.in_scope(|| items.iter()
+                                .enumerate()
+                                .map(|(i, item)| map(chunk * chunk_length + i, item))
+                                .collect::<Vec<T>>()))
+                        })
+                        .collect();
+                    join_all(handles).flatten().collect()
+                })
+            }
+            Strategy::Shared => {
+                // Results arrive in any order, so collect them with their index and sort them afterwards
+                let mut results: Vec<(usize, T)> = self.map_reduce(
+                    items,
+                    Vec::new,
+                    |i, item| vec![(i, map(i, item))],
+                    |mut results, other| {
+                        results.extend(other);
+                        results
+                    },
+                );
+                info_span!("reduce").in_scope(|| {
+                    results.sort_unstable_by_key(|&(i, _)| i);
+                    results.into_iter().map(|(_, result)| result).collect()
+                })
+            }
+        }
+    }
+
+    /// Parse every line of the input and collect the results in the order of the lines, failing with the error on the earliest line
+    pub fn try_collect_lines<T, M>(&self, input: &str, map: M) -> Result<Vec<T>, ParseError>
+    where
+        T: Send,
+        M: Fn(&str) -> Result<T, ParseError> + Sync,
+    {
+        self.map_collect(&lines(input), |i, line| map(line).map_err(|err| err.at_line(i)))
+            .into_iter()
+            .collect()
+    }
+
+    /// Solve the puzzle by mapping every line of the input with the line solver and combining the results
+    ///
+    /// Mapping the lines parses them, so the calling thread waits for the lines to be mapped and combined in a `parse` span.
+    pub fn solve<S: LineSolver>(&self, solver: &S, input: &str) -> Result<S::Answer, ParseError> {
+        let result = info_span!("parse").in_scope(|| self.map_reduce(
+            &lines(input),
+            || Ok(solver.identity()),
+            |i, line| solver.map(i, line).map_err(|err| err.at_line(i)),
+            |a, b| first_error(a, b, |a, b| solver.combine(a, b)),
+        ))?;
+        info_span!("reduce").in_scope(|| solver.finish(result))
+    }
+
+    /// Solve the puzzle by mapping every line with the line solver as soon as it is read, without reading the whole input into memory
+    ///
+    /// The length of a stream is not known up front, so the chunked and shared strategies both let every thread take the next line from the reader.
+    /// Lines that cannot be parsed are reported as invalid data.
+    pub fn solve_reader<S: LineSolver>(&self, solver: &S, reader: impl BufRead + Send) -> io::Result<S::Answer> {
+        // Stop at the first line that cannot be read, and report it once the lines before it are mapped
+        let failure = Mutex::new(None);
+        let lines = reader.lines()
+            .map_while(|line| line.map_err(|err| *failure.lock().unwrap() = Some(err)).ok())
+            .enumerate();
+
+        let identity = || Ok(solver.identity());
+        let map = |i, line: String| solver.map(i, &line).map_err(|err| err.at_line(i));
+        let reduce = |a, b| first_error(a, b, |a, b| solver.combine(a, b));
+
+        // Reading and mapping the lines parses them, like in [Executor::solve]
+        let parse = info_span!("parse");
+        let result = parse.in_scope(|| match self.strategy {
+            Strategy::Sequential => lines.fold(identity(), |result, (i, line)| reduce(result, map(i, line))),
+            Strategy::Rayon => self.install(|| lines.par_bridge()
+                .map_init(|| self.worker(&parse).entered(), |_, (i, line)| map(i, line))
+                .reduce(identity, reduce)),
+            Strategy::Chunked | Strategy::Shared => self.map_reduce_shared(lines, identity, map, reduce),
+        });
+
+        if let Some(err) = failure.into_inner().unwrap() {
+            return Err(err);
+        }
+        result
+            .and_then(|result| info_span!("reduce").in_scope(|| solver.finish(result)))
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    // Let every thread take the next item from an iterator shared behind a mutex, and reduce the results of the threads
+    fn map_reduce_shared<I, X, T, M, R>(&self, items: I, identity: impl Fn() -> T + Sync, map: M, reduce: R) -> T
+    where
+        I: Iterator<Item = (usize, X)> + Send,
+        T: Send,
+        M: Fn(usize, X) -> T + Sync,
+        R: Fn(T, T) -> T + Sync,
+    {
+        let parent = Span::current();
+        let items = Mutex::new(items);
+        let (items, identity, map, reduce) = (&items, &identity, &map, &reduce);
+        let results: Vec<T> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..self.threads)
+                .map(|_| {
+                    let worker = self.worker(&parent);
+                    scope.spawn(move || worker.in_scope(|| {
+                        // Only hold the lock while taking the next item
+                        iter::from_fn(|| items.lock().unwrap().next())
+                            .fold(identity(), |result, (i, item)| reduce(result, map(i, item)))
+                    }))
+                })
+                .collect();
+            join_all(handles).collect()
+        });
+        info_span!("reduce").in_scope(|| results.into_iter().fold(identity(), reduce))
+    }
+
+    // Run a closure inside the dedicated rayon pool if there is one
+    fn install<T: Send>(&self, op: impl FnOnce() -> T + Send) -> T {
+        match &self.pool {
+            Some(pool) => pool.install(op),
+            None => op(),
+        }
+    }
+
+    // Span of the work a single thread does, recorded under the span of the caller
+    fn worker(&self, parent: &Span) -> Span {
+        info_span!(parent: parent, "worker", strategy = %self.strategy)
+    }
+
+    // Length of the chunks when splitting the given number of items over the threads
+    fn chunk_length(&self, items: usize) -> usize {
+        items.div_ceil(self.threads).max(1)
+    }
+}
+
+impl Default for Executor {
+    /// Use rayon with its global thread pool
+    fn default() -> Executor {
+        Executor::new(Strategy::Rayon, None)
+    }
+}
+
+/// Split the input into lines, the unit of work of the line-oriented days
+pub fn lines(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+// Wait for all threads to finish, resuming the panic of a thread that panicked
+fn join_all<'scope, T: 'scope>(handles: Vec<ScopedJoinHandle<'scope, T>>) -> impl Iterator<Item = T> + 'scope {
+    handles.into_iter()
+        .map(|handle| handle.join().unwrap_or_else(|payload| panic::resume_unwind(payload)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn executors() -> Vec<Executor> {
+        Strategy::ALL.into_iter()
+            .flat_map(|strategy| [1, 3, 64].map(|threads| Executor::new(strategy, Some(threads))))
+            .collect()
+    }
+
+    #[test]
+    fn strategies_agree() {
+        let input = (1..=1000).map(|i| i.to_string()).collect::<Vec<_>>().join("\n");
+        for executor in executors() {
+            let sum: u64 = executor.map_reduce(&lines(&input), || 0, |_, line| line.parse::<u64>().unwrap(), |a, b| a + b);
+            assert_eq!(sum, 500500, "{:?}", executor);
+        }
+    }
+
+    #[test]
+    fn collect_preserves_order() {
+        let items: Vec<usize> = (0..1000).collect();
+        for executor in executors() {
+            assert_eq!(executor.map_collect(&items, |i, &item| i * item), items.iter().map(|i| i * i).collect::<Vec<_>>(), "{:?}", executor);
+        }
+    }
+
+    #[test]
+    fn empty_input() {
+        for executor in executors() {
+            assert_eq!(executor.map_reduce(&lines(""), || 0, |_, _| 1, |a, b| a + b), 0);
+            assert_eq!(executor.try_collect_lines("", |line| Ok(line.len())), Ok(Vec::new()));
+        }
+    }
+
+    // Sum the numbers on all lines, and count the lines to check every line is combined exactly once
+    struct Numbers;
+
+    impl LineSolver for Numbers {
+        type Accumulator = (u64, usize);
+        type Answer = (u64, usize);
+
+        fn identity(&self) -> (u64, usize) {
+            (0, 0)
+        }
+
+        fn map(&self, _: usize, line: &str) -> Result<(u64, usize), ParseError> {
+            line.parse()
+                .map(|number| (number, 1))
+                .map_err(|_| ParseError::new(line, 0, "a number"))
+        }
+
+        fn combine(&self, a: (u64, usize), b: (u64, usize)) -> (u64, usize) {
+            (a.0 + b.0, a.1 + b.1)
+        }
+
+        fn finish(&self, result: (u64, usize)) -> Result<(u64, usize), ParseError> {
+            Ok(result)
+        }
+    }
+
+    #[test]
+    fn line_solver() {
+        let input = (1..=1000).map(|i| i.to_string()).collect::<Vec<_>>().join("\n");
+        for executor in executors() {
+            assert_eq!(executor.solve(&Numbers, &input), Ok((500500, 1000)), "{:?}", executor);
+            assert_eq!(executor.solve_reader(&Numbers, input.as_bytes()).unwrap(), (500500, 1000), "{:?}", executor);
+            assert_eq!(executor.solve(&Numbers, ""), Ok((0, 0)), "{:?}", executor);
+        }
+    }
+
+    #[test]
+    fn line_solver_reports_the_earliest_error() {
+        let input = (1..=1000).map(|i| if i % 300 == 0 { "x".to_string() } else { i.to_string() }).collect::<Vec<_>>().join("\n");
+        for executor in executors() {
+            assert_eq!(executor.solve(&Numbers, &input).unwrap_err().line, 300, "{:?}", executor);
+            let err = executor.solve_reader(&Numbers, input.as_bytes()).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+            assert!(err.to_string().starts_with("line 300,"), "{:?}: {}", executor, err);
+        }
+    }
+
+    #[test]
+    fn parse_strategy() {
+        for strategy in Strategy::ALL {
+            assert_eq!(strategy.to_string().parse(), Ok(strategy));
+        }
+        assert!("fibers".parse::<Strategy>().is_err());
+    }
+}
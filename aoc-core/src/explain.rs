@@ -0,0 +1,164 @@
+use std::fmt::Display;
+
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+use crate::error::ParseError;
+
+/// What a single line contributes to the answer, as named fields in the order they are shown
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Explanation {
+    fields: Vec<(&'static str, Field)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Field {
+    Number(String),
+    Text(String),
+}
+
+impl Explanation {
+    /// An explanation without any fields
+    pub fn new() -> Explanation {
+        Explanation::default()
+    }
+
+    /// Add a number, which is right-aligned in tables and a json number when it fits in 64 bits
+    pub fn number(mut self, name: &'static str, value: impl Display) -> Explanation {
+        self.fields.push((name, Field::Number(value.to_string())));
+        self
+    }
+
+    /// Add a piece of text, which is left-aligned in tables and a json string
+    pub fn text(mut self, name: &'static str, value: impl Display) -> Explanation {
+        self.fields.push((name, Field::Text(value.to_string())));
+        self
+    }
+
+    /// The explanation as a single line of json, labelled with the day, part and the line number starting at 1
+    pub fn to_json(&self, day: u32, part: u32, line: usize) -> String {
+        serde_json::to_string(&Row { day, part, line, explanation: self }).expect("Serializing an explanation cannot fail")
+    }
+}
+
+struct Row<'a> {
+    day: u32,
+    part: u32,
+    line: usize,
+    explanation: &'a Explanation,
+}
+
+impl Serialize for Row<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.explanation.fields.len() + 3))?;
+        map.serialize_entry("day", &self.day)?;
+        map.serialize_entry("part", &self.part)?;
+        map.serialize_entry("line", &self.line)?;
+        for (name, field) in &self.explanation.fields {
+            match field {
+                Field::Number(value) => match (value.parse::<i64>(), value.parse::<u64>()) {
+                    (Ok(value), _) => map.serialize_entry(name, &value)?,
+                    (_, Ok(value)) => map.serialize_entry(name, &value)?,
+                    // Keep numbers that do not fit in 64 bits exact by writing them as strings
+                    _ => map.serialize_entry(name, value)?,
+                },
+                Field::Text(value) => map.serialize_entry(name, value)?,
+            }
+        }
+        map.end()
+    }
+}
+
+/// Explain every line of an input, failing on the first line that does not parse
+pub fn lines(input: &str, explain: impl Fn(&str) -> Result<Explanation, ParseError>) -> Result<Vec<Explanation>, ParseError> {
+    input.lines()
+        .enumerate()
+        .map(|(index, line)| explain(line).map_err(|err| err.at_line(index)))
+        .collect()
+}
+
+/// Lay out the explanations of the lines of an input as a table, with a column for the line number, one for each
+/// field of the first explanation and one for the line itself
+pub fn table(input: &str, explanations: &[Explanation]) -> String {
+    let Some(first) = explanations.first() else {
+        return String::new();
+    };
+    let mut header = vec!["line".to_string()];
+    header.extend(first.fields.iter().map(|(name, _)| name.to_string()));
+    header.push("input".to_string());
+    let rows = explanations.iter()
+        .zip(input.lines())
+        .enumerate()
+        .map(|(index, (explanation, line))| {
+            let mut row = vec![(index + 1).to_string()];
+            row.extend(explanation.fields.iter().map(|(_, field)| match field {
+                Field::Number(value) | Field::Text(value) => value.clone(),
+            }));
+            row.push(line.to_string());
+            row
+        })
+        .collect::<Vec<_>>();
+
+    // Numbers, including the line number, are right-aligned and everything else is left-aligned
+    let right = |column: usize| column == 0 || matches!(first.fields.get(column - 1), Some((_, Field::Number(_))));
+    let widths = (0..header.len())
+        .map(|column| iter_column(&header, &rows, column).map(|cell| cell.chars().count()).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+    let mut table = String::new();
+    for row in std::iter::once(&header).chain(&rows) {
+        let cells = row.iter()
+            .enumerate()
+            .map(|(column, cell)| match column {
+                _ if column == row.len() - 1 => cell.clone(),
+                _ if right(column) => format!("{:>1$}", cell, widths[column]),
+                _ => format!("{:<1$}", cell, widths[column]),
+            })
+            .collect::<Vec<_>>();
+        table.push_str(&cells.join("  "));
+        table.push('\n');
+    }
+    table
+}
+
+// The cells of a column, including its header
+fn iter_column<'a>(header: &'a [String], rows: &'a [Vec<String>], column: usize) -> impl Iterator<Item = &'a String> {
+    std::iter::once(header).chain(rows.iter().map(Vec::as_slice)).filter_map(move |row| row.get(column))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explain(line: &str) -> Result<Explanation, ParseError> {
+        let value = line.parse::<u128>().map_err(|_| ParseError::new(line, 0, "a number"))?;
+        Ok(Explanation::new().text("parity", if value % 2 == 0 { "even" } else { "odd" }).number("double", value * 2))
+    }
+
+    #[test]
+    fn table() {
+        let input = "7\n12\n";
+        let explanations = lines(input, explain).unwrap();
+        assert_eq!(super::table(input, &explanations), concat!(
+            "line  parity  double  input\n",
+            "   1  odd         14  7\n",
+            "   2  even        24  12\n",
+        ));
+        assert_eq!(super::table("", &[]), "");
+    }
+
+    #[test]
+    fn json() {
+        let explanations = lines("3\n", explain).unwrap();
+        assert_eq!(explanations[0].to_json(9, 1, 1), r#"{"day":9,"part":1,"line":1,"parity":"odd","double":6}"#);
+        assert_eq!(
+            Explanation::new().number("value", u128::MAX).to_json(9, 1, 2),
+            r#"{"day":9,"part":1,"line":2,"value":"340282366920938463463374607431768211455"}"#,
+        );
+        assert_eq!(Explanation::new().number("value", -1).to_json(9, 2, 1), r#"{"day":9,"part":2,"line":1,"value":-1}"#);
+    }
+
+    #[test]
+    fn errors() {
+        let err = lines("1\nx\n", explain).unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+}
@@ -0,0 +1,266 @@
+use std::ops::{Index, IndexMut};
+
+use crate::error::ParseError;
+use crate::executor::Executor;
+
+/// Position of a cell in a grid as its row and column
+pub type Position = (usize, usize);
+
+// Offsets of the orthogonal neighbours of a cell, followed by its diagonal neighbours
+const OFFSETS: [(isize, isize); 8] = [(-1, 0), (0, -1), (0, 1), (1, 0), (-1, -1), (-1, 1), (1, -1), (1, 1)];
+
+/// A rectangular grid of cells, stored row after row
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    height: usize,
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid of the given size with every cell set to the same value
+    pub fn new(height: usize, width: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid { height, width, cells: vec![value; height * width] }
+    }
+
+    /// Parse a grid with a row for every line of the input, mapping every character to a cell and processing the lines with the executor
+    ///
+    /// Fails at the earliest character the cell function rejects, with `expected` describing the characters it accepts,
+    /// or at the earliest line that is not as wide as the first one.
+    pub fn parse<F>(input: &str, executor: &Executor, expected: &str, cell: F) -> Result<Grid<T>, ParseError>
+    where
+        T: Send,
+        F: Fn(char) -> Option<T> + Sync,
+    {
+        let rows: Vec<Vec<T>> = executor.try_collect_lines(input, |line| line.char_indices()
+            .map(|(offset, c)| cell(c).ok_or_else(|| ParseError::new(line, offset, expected)))
+            .collect())?;
+
+        // Every row has to be as wide as the first one
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(index) = rows.iter().position(|row| row.len() != width) {
+            let line = input.lines().nth(index).unwrap_or_default();
+            let offset = line.char_indices().nth(width).map_or(line.len(), |(offset, _)| offset);
+            return Err(ParseError::new(line, offset, format!("a line of {} characters", width)).at_line(index));
+        }
+
+        Ok(Grid { height: rows.len(), width, cells: rows.into_iter().flatten().collect() })
+    }
+
+    /// Build a grid from lines of varying length, mapping every character to a cell and filling the cells past the end of the shorter lines
+    pub fn padded<'a>(lines: impl IntoIterator<Item = &'a str>, fill: T, cell: impl Fn(char) -> T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let rows: Vec<Vec<T>> = lines.into_iter()
+            .map(|line| line.chars().map(&cell).collect())
+            .collect();
+
+        let (height, width) = (rows.len(), rows.iter().map(Vec::len).max().unwrap_or(0));
+        let cells = rows.into_iter()
+            .flat_map(|mut row| {
+                row.resize(width, fill.clone());
+                row
+            })
+            .collect();
+        Grid { height, width, cells }
+    }
+
+    /// Number of rows
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of columns
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Whether the position lies within the grid
+    pub fn contains(&self, (row, column): Position) -> bool {
+        row < self.height && column < self.width
+    }
+
+    /// The cell at the position, if it lies within the grid
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position).then(|| &self.cells[position.0 * self.width + position.1])
+    }
+
+    /// The cell at the position to change, if it lies within the grid
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.contains(position).then(|| &mut self.cells[position.0 * self.width + position.1])
+    }
+
+    /// The positions of all cells, row after row
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.height).flat_map(|row| (0..self.width).map(move |column| (row, column)))
+    }
+
+    /// All cells together with their position, row after row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell, row after row, that matches the predicate
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.cells.iter()
+            .position(predicate)
+            .map(|index| (index / self.width, index % self.width))
+    }
+
+    /// The positions of the neighbours above, left, right and below a cell that lie within the grid
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &OFFSETS[..4])
+    }
+
+    /// The positions of the orthogonal and diagonal neighbours of a cell that lie within the grid
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &OFFSETS)
+    }
+
+    /// The cells of a row
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// The rows of the grid from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// The cells of a column from top to bottom
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.width, "column {} is outside of a grid of width {}", column, self.width);
+        (0..self.height).map(move |row| &self.cells[row * self.width + column])
+    }
+
+    /// The columns of the grid from left to right
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// Swap the rows and columns of the grid
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            height: self.width,
+            width: self.height,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    /// Map every cell to a new grid of the same size
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid { height: self.height, width: self.width, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// Mark every cell that can be reached from the start by stepping to orthogonal neighbours,
+    /// where `connected` tells whether a step from a position to its neighbour is possible
+    pub fn flood_fill(&self, start: Position, connected: impl Fn(Position, Position) -> bool) -> Grid<bool> {
+        let mut filled = Grid::new(self.height, self.width, false);
+        filled[start] = true;
+
+        let mut stack = vec![start];
+        while let Some(position) = stack.pop() {
+            for neighbour in self.neighbours4(position) {
+                if !filled[neighbour] && connected(position, neighbour) {
+                    filled[neighbour] = true;
+                    stack.push(neighbour);
+                }
+            }
+        }
+
+        filled
+    }
+
+    // The positions at the given offsets from a cell that lie within the grid
+    fn neighbours<'g>(&'g self, (row, column): Position, offsets: &'g [(isize, isize)]) -> impl Iterator<Item = Position> + 'g {
+        offsets.iter()
+            .filter_map(move |&(d_row, d_column)| Some((row.checked_add_signed(d_row)?, column.checked_add_signed(d_column)?)))
+            .filter(|&position| self.contains(position))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("position {:?} is outside of a grid of {} by {}", position, self.height, self.width))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("position {:?} is outside of a grid of {} by {}", position, height, width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, &Executor::sequential(), "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn parse() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6]);
+        assert_eq!(grid.position(|&cell| cell > 4), Some((1, 1)));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(digits(""), Ok(Grid::new(0, 0, 0)));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(digits("12\n3x\n").unwrap_err().to_string(), "line 2, column 2: expected a digit, found 'x'");
+        assert_eq!(digits("12\n3\n").unwrap_err().to_string(), "line 2, column 2: expected a line of 2 characters, found the end of the line");
+        assert_eq!(digits("12\n345\n").unwrap_err().to_string(), "line 2, column 3: expected a line of 2 characters, found '5'");
+    }
+
+    #[test]
+    fn padded() {
+        let grid = Grid::padded(["ab", "", "abcd"], '.', |c| c);
+        assert_eq!((grid.height(), grid.width()), (3, 4));
+        assert_eq!(grid.rows().map(|row| row.iter().collect()).collect::<Vec<String>>(), ["ab..", "....", "abcd"]);
+        assert_eq!(Grid::padded(["", ""], '.', |c| c).rows().count(), 2);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(3, 4, ());
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), [(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((2, 3)).collect::<Vec<_>>(), [(1, 3), (2, 2), (1, 2)]);
+    }
+
+    #[test]
+    fn transpose() {
+        let grid = digits("123\n456\n").unwrap();
+        let transposed = grid.transpose();
+        assert_eq!(transposed.rows().collect::<Vec<_>>(), [[1, 4], [2, 5], [3, 6]]);
+        assert_eq!(transposed.transpose(), grid);
+    }
+
+    #[test]
+    fn flood_fill() {
+        // Fill the region of 1s around the top left corner, which reaches the top right corner around the 2s but not the 1 below them
+        let grid = digits("1121\n1221\n1111\n2222\n1111\n").unwrap();
+        let filled = grid.flood_fill((0, 0), |from, to| grid[from] == grid[to]);
+        assert_eq!(filled.iter().filter(|&(_, &filled)| filled).count(), 9);
+        assert!(!filled[(1, 1)] && filled[(0, 3)] && !filled[(3, 0)] && !filled[(4, 0)]);
+    }
+}
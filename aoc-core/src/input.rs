@@ -0,0 +1,158 @@
+use std::env;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::ops::Deref;
+use std::path::PathBuf;
+use std::str;
+
+use memmap2::Mmap;
+
+/// Environment variable that overrides the default input file
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+/// Expands to the path of the `input` file shared by both parts of a day,
+/// which lives next to the `part1` and `part2` directories.
+#[macro_export]
+macro_rules! input_path {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../input")
+    };
+}
+
+/// Where to read the puzzle input from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// Read the input from a file
+    File(PathBuf),
+    /// Read the input from stdin
+    Stdin,
+}
+
+impl Source {
+    /// Select the input source, in order of preference from the given argument,
+    /// the `AOC_INPUT` environment variable or the default path
+    pub fn select(argument: Option<OsString>, default: impl Into<PathBuf>) -> Source {
+        argument
+            .or_else(|| env::var_os(INPUT_VAR))
+            .map_or_else(|| Source::File(default.into()), Source::from)
+    }
+
+    /// Read the whole input to a string
+    pub fn read(&self) -> io::Result<String> {
+        let result = match self {
+            Source::File(path) => fs::read_to_string(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+
+        result.map_err(|err| self.error(err))
+    }
+
+    /// Memory-map the input if it is a file, or read it into memory otherwise
+    pub fn map(&self) -> io::Result<Input> {
+        let data = match self {
+            Source::File(path) => File::open(path).and_then(|file| {
+                // Empty files cannot be mapped on every platform
+                if file.metadata()?.len() == 0 {
+                    return Ok(Data::Owned(Vec::new()));
+                }
+
+                // SAFETY: the input files are not expected to be modified while a solver runs, which is why watch mode
+                // reads the inputs it watches instead of mapping them
+                unsafe { Mmap::map(&file) }.map(Data::Mapped)
+            }),
+            Source::Stdin => {
+                let mut input = Vec::new();
+                io::stdin().read_to_end(&mut input).map(|_| Data::Owned(input))
+            }
+        };
+
+        data.and_then(Input::new).map_err(|err| self.error(err))
+    }
+
+    /// Open the input to read it as a stream, without reading it into memory
+    pub fn reader(&self) -> io::Result<Box<dyn Read + Send>> {
+        match self {
+            Source::File(path) => File::open(path)
+                .map(|file| Box::new(file) as Box<dyn Read + Send>)
+                .map_err(|err| self.error(err)),
+            Source::Stdin => Ok(Box::new(io::stdin())),
+        }
+    }
+
+    // Add the source to an error
+    fn error(&self, err: io::Error) -> io::Error {
+        io::Error::new(err.kind(), format!("Could not read input from {}: {}", self, err))
+    }
+}
+
+impl From<OsString> for Source {
+    /// Interpret `-` as stdin and anything else as a path
+    fn from(argument: OsString) -> Source {
+        if argument == "-" {
+            Source::Stdin
+        } else {
+            Source::File(argument.into())
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+/// Puzzle input that is memory-mapped when it comes from a file, so it can be used as text without copying
+pub struct Input {
+    data: Data,
+}
+
+enum Data {
+    Mapped(Mmap),
+    Owned(Vec<u8>),
+}
+
+impl Input {
+    // Wrap the data after checking that it is valid UTF-8
+    fn new(data: Data) -> io::Result<Input> {
+        let input = Input { data };
+        str::from_utf8(input.as_bytes()).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        Ok(input)
+    }
+
+    /// The raw bytes of the input
+    pub fn as_bytes(&self) -> &[u8] {
+        match &self.data {
+            Data::Mapped(mmap) => mmap,
+            Data::Owned(bytes) => bytes,
+        }
+    }
+
+    /// The input as text
+    pub fn as_str(&self) -> &str {
+        // SAFETY: the bytes were checked to be valid UTF-8 when the input was created
+        unsafe { str::from_utf8_unchecked(self.as_bytes()) }
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<String> for Input {
+    fn from(input: String) -> Input {
+        Input { data: Data::Owned(input.into_bytes()) }
+    }
+}
@@ -0,0 +1,262 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+// Number of digits that always fit in 64 bits
+const SHORT_DIGITS: usize = 19;
+
+/// An integer type that numbers can be read into, with a minus sign for signed types
+pub trait Integer: Sized {
+    /// Whether the type has negative numbers
+    const SIGNED: bool;
+
+    /// The number with the given magnitude and sign, if it fits in the type
+    fn from_u64(magnitude: u64, negative: bool) -> Option<Self>;
+
+    /// The number with the given magnitude and sign, if it fits in the type
+    fn from_u128(magnitude: u128, negative: bool) -> Option<Self>;
+}
+
+macro_rules! unsigned {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            const SIGNED: bool = false;
+
+            fn from_u64(magnitude: u64, negative: bool) -> Option<$t> {
+                if negative && magnitude != 0 {
+                    return None;
+                }
+                <$t>::try_from(magnitude).ok()
+            }
+
+            fn from_u128(magnitude: u128, negative: bool) -> Option<$t> {
+                if negative && magnitude != 0 {
+                    return None;
+                }
+                <$t>::try_from(magnitude).ok()
+            }
+        }
+    )*};
+}
+
+macro_rules! signed {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            const SIGNED: bool = true;
+
+            fn from_u64(magnitude: u64, negative: bool) -> Option<$t> {
+                let magnitude = i128::from(magnitude);
+                <$t>::try_from(if negative { -magnitude } else { magnitude }).ok()
+            }
+
+            fn from_u128(magnitude: u128, negative: bool) -> Option<$t> {
+                let number = if negative { 0_i128.checked_sub_unsigned(magnitude)? } else { i128::try_from(magnitude).ok()? };
+                <$t>::try_from(number).ok()
+            }
+        }
+    )*};
+}
+
+unsigned!(u8, u16, u32, u64, u128, usize);
+signed!(i8, i16, i32, i64, i128, isize);
+
+/// Why no number could be read
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntError {
+    /// There are no digits where the number should be
+    Missing,
+    /// The number does not fit in the type
+    Overflow,
+}
+
+impl fmt::Display for IntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            IntError::Missing => "no digits",
+            IntError::Overflow => "number too large",
+        })
+    }
+}
+
+/// Read the number at the start of the bytes, returning it together with the number of bytes it takes up
+///
+/// Signed types take an optional minus sign before the digits.
+pub fn number<T: Integer>(bytes: &[u8]) -> Result<(T, usize), IntError> {
+    let negative = T::SIGNED && bytes.first() == Some(&b'-');
+    let digits = digits(&bytes[negative as usize..]);
+    if digits.is_empty() {
+        return Err(IntError::Missing);
+    }
+
+    let number = if digits.len() <= SHORT_DIGITS {
+        T::from_u64(short(digits), negative)
+    } else {
+        long(digits).and_then(|magnitude| T::from_u128(magnitude, negative))
+    };
+    number.map(|number| (number, negative as usize + digits.len())).ok_or(IntError::Overflow)
+}
+
+/// Read the next number at or after the position, and move the position past it
+///
+/// Skips everything up to the first digit, or the minus sign in front of it for signed types.
+/// Returns `None` if there are no digits left.
+pub fn next_number<T: Integer>(bytes: &[u8], position: &mut usize) -> Option<Result<T, IntError>> {
+    let start = *position + bytes.get(*position..)?.iter().position(u8::is_ascii_digit)?;
+
+    // Include a minus sign directly in front of the digits
+    let start = if T::SIGNED && start > *position && bytes[start - 1] == b'-' { start - 1 } else { start };
+
+    Some(match number(&bytes[start..]) {
+        Ok((number, length)) => {
+            *position = start + length;
+            Ok(number)
+        }
+        Err(err) => {
+            // Skip the digits of a number that does not fit
+            let sign = (bytes[start] == b'-') as usize;
+            *position = start + sign + digits(&bytes[start + sign..]).len();
+            Err(err)
+        }
+    })
+}
+
+/// Iterate over all numbers in the bytes, skipping everything in between them
+pub fn numbers<T: Integer>(bytes: &[u8]) -> Numbers<'_, T> {
+    Numbers { bytes, position: 0, integer: PhantomData }
+}
+
+/// Read all digits of the bytes as a single number, skipping everything in between them
+pub fn concat_digits<T: Integer>(bytes: &[u8]) -> Result<T, IntError> {
+    let mut magnitude: Option<u128> = None;
+    let mut rest = bytes;
+    while let Some(start) = rest.iter().position(u8::is_ascii_digit) {
+        let run = digits(&rest[start..]);
+        let run_value = long(run).ok_or(IntError::Overflow)?;
+        magnitude = Some(match magnitude {
+            // Leading zeros do not shift the digits after them
+            None | Some(0) => run_value,
+            Some(magnitude) => 10_u128.checked_pow(run.len() as u32)
+                .and_then(|shift| magnitude.checked_mul(shift))
+                .and_then(|magnitude| magnitude.checked_add(run_value))
+                .ok_or(IntError::Overflow)?,
+        });
+        rest = &rest[start + run.len()..];
+    }
+
+    T::from_u128(magnitude.ok_or(IntError::Missing)?, false).ok_or(IntError::Overflow)
+}
+
+/// Iterator over all numbers in a byte slice, see [numbers]
+#[derive(Clone, Debug)]
+pub struct Numbers<'a, T> {
+    bytes: &'a [u8],
+    position: usize,
+    integer: PhantomData<T>,
+}
+
+impl<T: Integer> Iterator for Numbers<'_, T> {
+    type Item = Result<T, IntError>;
+
+    fn next(&mut self) -> Option<Result<T, IntError>> {
+        next_number(self.bytes, &mut self.position)
+    }
+}
+
+// The digits at the start of the bytes
+fn digits(bytes: &[u8]) -> &[u8] {
+    let length = bytes.iter().position(|byte| !byte.is_ascii_digit()).unwrap_or(bytes.len());
+    &bytes[..length]
+}
+
+// Value of at most 19 digits, converting 8 digits at once
+fn short(digits: &[u8]) -> u64 {
+    let mut chunks = digits.chunks_exact(8);
+    let value = chunks.by_ref().fold(0, |value, chunk| value * 100_000_000 + eight_digits(chunk.try_into().unwrap()));
+    chunks.remainder().iter().fold(value, |value, &digit| value * 10 + u64::from(digit - b'0'))
+}
+
+// Value of any number of digits, if it fits in 128 bits
+fn long(digits: &[u8]) -> Option<u128> {
+    digits.chunks(SHORT_DIGITS).try_fold(0_u128, |value, chunk| value
+        .checked_mul(10_u128.pow(chunk.len() as u32))?
+        .checked_add(u128::from(short(chunk))))
+}
+
+// Value of 8 digits, combining neighbouring digits into pairs, pairs into quads and quads into the value within a single word
+fn eight_digits(digits: [u8; 8]) -> u64 {
+    // The first digit is the lowest byte, so the higher neighbour of every lane is the less significant one
+    let word = u64::from_le_bytes(digits) - 0x3030_3030_3030_3030;
+    let word = (word * 10 + (word >> 8)) & 0x00ff_00ff_00ff_00ff;
+    let word = (word * 100 + (word >> 16)) & 0x0000_ffff_0000_ffff;
+    (word * 10_000 + (word >> 32)) & 0xffff_ffff
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn reads_numbers() {
+        assert_eq!(number::<u32>(b"467..114"), Ok((467, 3)));
+        assert_eq!(number::<i64>(b"-48 | 83"), Ok((-48, 3)));
+        assert_eq!(number::<u8>(b"-48"), Err(IntError::Missing));
+        assert_eq!(number::<u8>(b"256"), Err(IntError::Overflow));
+        assert_eq!(number::<i8>(b"-128"), Ok((-128, 4)));
+        assert_eq!(number::<i8>(b"128"), Err(IntError::Overflow));
+        assert_eq!(number::<u64>(b"00000000000000000000000000000000000000000042"), Ok((42, 44)));
+        assert_eq!(number::<u32>(b""), Err(IntError::Missing));
+    }
+
+    #[test]
+    fn reads_all_numbers() {
+        let line = b"Game 12: 3 blue, -4 red;99999999999 green";
+        assert_eq!(numbers::<i64>(line).collect::<Vec<_>>(), [Ok(12), Ok(3), Ok(-4), Ok(99999999999)]);
+        assert_eq!(numbers::<u32>(line).collect::<Vec<_>>(), [Ok(12), Ok(3), Ok(4), Err(IntError::Overflow)]);
+
+        let mut position = 8;
+        assert_eq!(next_number::<u32>(line, &mut position), Some(Ok(3)));
+        assert_eq!(&line[position..position + 5], b" blue");
+        assert_eq!(next_number::<u32>(b"no numbers", &mut 0), None);
+    }
+
+    #[test]
+    fn concatenates_digits() {
+        assert_eq!(concat_digits::<u64>(b"Time:      7  15   30"), Ok(71530));
+        assert_eq!(concat_digits::<u64>(b"0 0000000000000000000000000000000000000000 12"), Ok(12));
+        assert_eq!(concat_digits::<u64>(b"18446744073709551615"), Ok(u64::MAX));
+        assert_eq!(concat_digits::<u64>(b"1844674407 3709551616"), Err(IntError::Overflow));
+        assert_eq!(concat_digits::<u64>(b"Time:"), Err(IntError::Missing));
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_str_parse(text in "-?[0-9]{1,45}") {
+            // Every type reads the same numbers as `str::parse`, and fails where it overflows
+            fn check<T: Integer + std::str::FromStr + PartialEq + fmt::Debug>(text: &str) -> Result<(), TestCaseError> {
+                let expected = text.parse::<T>().ok();
+                let found = number::<T>(text.as_bytes());
+                if expected.is_some() || !T::SIGNED && text.starts_with('-') {
+                    prop_assert_eq!(found.ok().map(|(number, _)| number), expected);
+                } else {
+                    prop_assert_eq!(found, Err(IntError::Overflow));
+                }
+                Ok(())
+            }
+
+            check::<u8>(&text)?;
+            check::<u32>(&text)?;
+            check::<u64>(&text)?;
+            check::<u128>(&text)?;
+            check::<i16>(&text)?;
+            check::<i64>(&text)?;
+            check::<i128>(&text)?;
+        }
+
+        #[test]
+        fn eight_digits_at_once(value in 0_u64..100_000_000) {
+            let digits: [u8; 8] = format!("{:08}", value).into_bytes().try_into().unwrap();
+            prop_assert_eq!(eight_digits(digits), value);
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! Shared helpers used by every day of Advent of Code 2023.
+
+pub mod chunks;
+pub mod error;
+pub mod executor;
+pub mod explain;
+pub mod grid;
+pub mod input;
+pub mod int;
+pub mod parse;
+pub mod report;
+pub mod solver;
+pub mod total;
+pub mod trace;
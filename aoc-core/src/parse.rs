@@ -0,0 +1,156 @@
+use std::any;
+
+use crate::error::ParseError;
+use crate::int::{self, IntError, Integer};
+
+/// The radix of all numbers in the puzzle inputs
+pub const RADIX: u32 = 10;
+
+/// Reads tokens from a line while keeping track of the position, to report where parsing failed
+#[derive(Clone, Debug)]
+pub struct Cursor<'a> {
+    line: &'a str,
+    offset: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// Start reading at the beginning of the line
+    pub fn new(line: &'a str) -> Cursor<'a> {
+        Cursor { line, offset: 0 }
+    }
+
+    /// The part of the line that has not been read yet
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.offset..]
+    }
+
+    /// The byte offset of the cursor in the line
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Error expecting the given token at the position of the cursor
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.error_at(self.offset, expected)
+    }
+
+    /// Error expecting the given token at a byte offset in the line
+    pub fn error_at(&self, offset: usize, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.line, offset, expected)
+    }
+
+    /// Skip any whitespace
+    pub fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.offset += rest.len() - rest.trim_start().len();
+    }
+
+    /// Whether only whitespace is left
+    pub fn at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.rest().is_empty()
+    }
+
+    /// Fail unless only whitespace is left
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        if self.at_end() {
+            Ok(())
+        } else {
+            Err(self.error("the end of the line"))
+        }
+    }
+
+    /// The next character after skipping whitespace, without reading it
+    pub fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.rest().chars().next()
+    }
+
+    /// Read the next character
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.rest().chars().next()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    /// Read the next `length` bytes, if there are that many left
+    pub fn take(&mut self, length: usize) -> Option<&'a str> {
+        let taken = self.rest().get(..length)?;
+        self.offset += length;
+        Some(taken)
+    }
+
+    /// Read the tag after skipping whitespace, or fail expecting it
+    pub fn tag(&mut self, tag: &str) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if !self.rest().starts_with(tag) {
+            return Err(self.error(format!("`{}`", tag)));
+        }
+        self.offset += tag.len();
+        Ok(())
+    }
+
+    /// Read the next word after skipping whitespace, i.e. everything up to the next whitespace
+    pub fn word(&mut self) -> Option<&'a str> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let length = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        self.offset += length;
+        (length > 0).then(|| &rest[..length])
+    }
+
+    /// Read a number after skipping whitespace, with a minus sign for signed types
+    pub fn number<T: Integer>(&mut self) -> Result<T, ParseError> {
+        self.skip_whitespace();
+        let (number, length) = int::number(self.rest().as_bytes()).map_err(|err| match err {
+            IntError::Missing => self.error("a number"),
+            IntError::Overflow => self.error(format!("a number that fits in {}", any::type_name::<T>())),
+        })?;
+        self.offset += length;
+        Ok(number)
+    }
+
+    /// Read numbers separated by whitespace up to the end of the line
+    pub fn numbers<T: Integer>(&mut self) -> Result<Vec<T>, ParseError> {
+        let mut numbers = Vec::new();
+        while !self.at_end() {
+            numbers.push(self.number()?);
+        }
+        Ok(numbers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_reads_tokens() {
+        let mut cursor = Cursor::new("Card  1: 41 -48 | 83");
+        assert_eq!(cursor.tag("Card"), Ok(()));
+        assert_eq!(cursor.number::<u32>(), Ok(1));
+        assert_eq!(cursor.tag(":"), Ok(()));
+        assert_eq!(cursor.number::<i32>(), Ok(41));
+        assert_eq!(cursor.number::<i32>(), Ok(-48));
+        assert_eq!(cursor.peek(), Some('|'));
+        assert_eq!(cursor.word(), Some("|"));
+        assert_eq!(cursor.numbers::<u8>(), Ok(vec![83]));
+        assert_eq!(cursor.end(), Ok(()));
+        assert_eq!(cursor.word(), None);
+    }
+
+    #[test]
+    fn cursor_errors() {
+        let mut cursor = Cursor::new("Game x: 300");
+        cursor.tag("Game").unwrap();
+        let err = cursor.number::<u8>().unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (6, "a number"));
+
+        cursor.next_char();
+        cursor.tag(":").unwrap();
+        let err = cursor.number::<u8>().unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (9, "a number that fits in u8"));
+        assert_eq!(cursor.take(4), None);
+        assert_eq!(cursor.take(3), Some("300"));
+    }
+}
@@ -0,0 +1,288 @@
+use std::env;
+use std::ffi::OsString;
+use std::fmt;
+use std::io::{self, Read};
+use std::process;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use tracing::info_span;
+
+use crate::error::ParseError;
+use crate::input::Source;
+use crate::trace;
+
+/// How answers are printed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// A sentence describing the answer, meant to be read by people
+    #[default]
+    Text,
+    /// A json object per answer together with its timings, meant to be read by scripts
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Format, String> {
+        match name {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Unknown format {}, expected text or json", name)),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Format::Text => "text",
+            Format::Json => "json",
+        })
+    }
+}
+
+/// The answer of a part of a day on an input, together with the time spent in each phase
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    /// Nanoseconds the solver spent in `parse` spans on the thread it was called on, which for the line solvers is the time
+    /// until every line is mapped and combined
+    pub parse_ns: u64,
+    /// Nanoseconds spent solving the puzzle outside of the `parse` spans
+    pub solve_ns: u64,
+    /// Number of threads the solver ran on
+    pub threads: usize,
+    /// SHA-256 of the input as lowercase hex
+    pub input_sha256: String,
+}
+
+impl Report {
+    /// The report as a single line of json
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Serializing a report cannot fail")
+    }
+}
+
+/// Hash an input to lowercase hex SHA-256
+pub fn sha256(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input.as_bytes()))
+}
+
+/// A reader that hashes everything read through it, to hash an input that is solved as a stream
+pub struct Sha256Reader<R> {
+    inner: R,
+    hasher: Sha256,
+}
+
+impl<R: Read> Sha256Reader<R> {
+    /// Hash everything read from the reader
+    pub fn new(inner: R) -> Sha256Reader<R> {
+        Sha256Reader { inner, hasher: Sha256::new() }
+    }
+
+    /// Lowercase hex SHA-256 of everything read so far, which matches [sha256] once the reader is read to the end
+    pub fn finish(self) -> String {
+        format!("{:x}", self.hasher.finalize())
+    }
+}
+
+impl<R: Read> Read for Sha256Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let length = self.inner.read(buf)?;
+        self.hasher.update(&buf[..length]);
+        Ok(length)
+    }
+}
+
+/// Run a closure and measure how long it took
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Run a solver and measure the wall time it spent in `parse` spans on the calling thread and how much longer it took to
+/// solve the puzzle, where the time in `parse` spans is only recorded once [trace::init] installed a subscriber
+pub fn time_phases<T>(f: impl FnOnce() -> T) -> (T, Duration, Duration) {
+    let ((result, parse_time), elapsed) = time(|| trace::parse_time(f));
+    (result, parse_time, elapsed.saturating_sub(parse_time))
+}
+
+/// Whole nanoseconds of a duration, saturating at centuries
+pub fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// Arguments of a day binary, i.e. `[--format text|json] [input]`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Args {
+    pub format: Format,
+    /// Path of the input, `-` for stdin
+    pub input: Option<OsString>,
+}
+
+impl Args {
+    /// Parse the arguments following the program name
+    pub fn parse(args: impl IntoIterator<Item = OsString>) -> Result<Args, String> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let format = match arg.to_str() {
+                Some("--format") => args.next().ok_or("Missing value for --format")?,
+                Some(arg) if arg.starts_with("--format=") => arg["--format=".len()..].into(),
+                _ if parsed.input.is_none() => {
+                    parsed.input = Some(arg);
+                    continue;
+                }
+                _ => return Err(format!("Unexpected argument {}", arg.to_string_lossy())),
+            };
+            parsed.format = format.to_string_lossy().parse()?;
+        }
+        Ok(parsed)
+    }
+
+    /// Parse the arguments of the process, exiting with a usage message if they are invalid
+    pub fn from_env() -> Args {
+        Args::parse(env::args_os().skip(1)).unwrap_or_else(|err| {
+            eprintln!("error: {}\nusage: [--format text|json] [input]", err);
+            process::exit(2);
+        })
+    }
+}
+
+/// Run a day binary: solve the input from the first argument, `-` for stdin, the `AOC_INPUT` environment variable or
+/// the default path, and print the answer after the label, or as a json [Report] when given `--format json`
+///
+/// Records the phases of the solver when the `AOC_TRACE` environment variable selects a [trace::Mode].
+///
+/// Exits the process with an error message if the input could not be read or parsed.
+pub fn main<T: fmt::Display>(day: u32, part: u32, label: &str, default: &str, threads: usize, solve: impl FnOnce(&str) -> Result<T, ParseError>) {
+    let args = Args::from_env();
+    let trace = trace::from_env().unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        process::exit(2);
+    });
+
+    // Load the input and solve the puzzle, timing the parsing and the solving apart
+    let input = info_span!("load").in_scope(|| Source::select(args.input, default).map()).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    });
+    let (answer, parse_time, solve_time) = time_phases(|| info_span!("day", day, part).in_scope(|| solve(&input)));
+    let answer = answer.unwrap_or_else(|err| err.exit());
+    // Print the breakdown or finish the trace before the answer
+    drop(trace);
+
+    match args.format {
+        Format::Text => println!("{}: {}", label, answer),
+        Format::Json => {
+            let report = Report {
+                day,
+                part,
+                answer: answer.to_string(),
+                parse_ns: nanos(parse_time),
+                solve_ns: nanos(solve_time),
+                threads,
+                input_sha256: sha256(&input),
+            };
+            println!("{}", report.to_json());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::Registry;
+
+    use crate::executor::{Executor, Strategy};
+    use crate::solver::LineSolver;
+
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(OsString::from))
+    }
+
+    #[test]
+    fn parse_args() {
+        assert_eq!(parse(&[]), Ok(Args::default()));
+        assert_eq!(parse(&["input"]), Ok(Args { format: Format::Text, input: Some("input".into()) }));
+        assert_eq!(parse(&["--format", "json", "-"]), Ok(Args { format: Format::Json, input: Some("-".into()) }));
+        assert_eq!(parse(&["-", "--format=json"]), Ok(Args { format: Format::Json, input: Some("-".into()) }));
+        assert!(parse(&["--format", "yaml"]).is_err());
+        assert!(parse(&["--format"]).is_err());
+        assert!(parse(&["a", "b"]).is_err());
+    }
+
+    #[test]
+    fn hashes_streams() {
+        let mut reader = Sha256Reader::new("abc".as_bytes());
+        io::copy(&mut reader, &mut io::sink()).unwrap();
+        assert_eq!(reader.finish(), sha256("abc"));
+    }
+
+    // Sleeps while mapping every line and while finishing, without parsing anything
+    struct Sleeper;
+
+    impl LineSolver for Sleeper {
+        type Accumulator = ();
+        type Answer = ();
+
+        fn identity(&self) {}
+
+        fn map(&self, _: usize, _: &str) -> Result<(), ParseError> {
+            thread::sleep(Duration::from_millis(5));
+            Ok(())
+        }
+
+        fn combine(&self, _: (), _: ()) {}
+
+        fn finish(&self, _: ()) -> Result<(), ParseError> {
+            thread::sleep(Duration::from_millis(20));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn phases_add_up_to_the_total() {
+        // The workers sleep for 80ms while mapping the lines, but the calling thread only waits about 20ms for them
+        let input = "line\n".repeat(16);
+        let subscriber = Registry::default().with(trace::ParseLayer);
+        tracing::subscriber::with_default(subscriber, || {
+            for strategy in [Strategy::Rayon, Strategy::Chunked, Strategy::Shared] {
+                let executor = Executor::new(strategy, Some(4));
+                let ((answer, parse_time, solve_time), total) = time(|| time_phases(|| executor.solve(&Sleeper, &input)));
+                assert_eq!(answer, Ok(()));
+                assert!(parse_time >= Duration::from_millis(20) && parse_time < Duration::from_millis(80), "{}: {:?}", strategy, parse_time);
+                assert!(solve_time >= Duration::from_millis(20), "{}: {:?}", strategy, solve_time);
+                assert!(total - (parse_time + solve_time) < Duration::from_millis(5), "{}: {:?} of {:?}", strategy, parse_time + solve_time, total);
+            }
+        });
+    }
+
+    #[test]
+    fn report_json() {
+        let report = Report {
+            day: 1,
+            part: 2,
+            answer: "281".to_string(),
+            parse_ns: 10,
+            solve_ns: 20,
+            threads: 4,
+            input_sha256: sha256("abc"),
+        };
+        assert_eq!(report.to_json(), concat!(
+            r#"{"day":1,"part":2,"answer":"281","parse_ns":10,"solve_ns":20,"threads":4,"#,
+            r#""input_sha256":"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"}"#,
+        ));
+    }
+}
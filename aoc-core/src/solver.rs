@@ -0,0 +1,27 @@
+use crate::error::ParseError;
+
+/// A puzzle that maps every line of the input on its own and combines the results into its answer
+///
+/// Lines are mapped on any thread and their results combined in any order, so [LineSolver::combine] should be
+/// associative and commutative, with [LineSolver::identity] as its identity. An [Executor](crate::executor::Executor)
+/// runs a line solver over an input in memory with [Executor::solve](crate::executor::Executor::solve), or over
+/// the lines of a reader as they are read with [Executor::solve_reader](crate::executor::Executor::solve_reader).
+pub trait LineSolver: Sync {
+    /// The combined result of any number of lines
+    type Accumulator: Send;
+    /// The answer of the puzzle
+    type Answer;
+
+    /// The result of no lines at all
+    fn identity(&self) -> Self::Accumulator;
+
+    /// Parse the line with the given index in the input and map it to its result,
+    /// errors are reported at the line by the executor
+    fn map(&self, index: usize, line: &str) -> Result<Self::Accumulator, ParseError>;
+
+    /// Combine the results of two disjoint sets of lines
+    fn combine(&self, a: Self::Accumulator, b: Self::Accumulator) -> Self::Accumulator;
+
+    /// Turn the combined result of all lines into the answer
+    fn finish(&self, result: Self::Accumulator) -> Result<Self::Answer, ParseError>;
+}
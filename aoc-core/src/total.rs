@@ -0,0 +1,138 @@
+use num::{BigInt, BigUint};
+
+use crate::error::ParseError;
+
+/// A total that the values of lines are added up in
+///
+/// [Checked] keeps the total in a primitive integer and detects when that overflows, and the big integers never overflow.
+/// Days pick one of them with their `checked` feature, so the same code sums in the fast integer type normally and
+/// widens it to 128 bits or a big integer in checked builds, where either build reports overflow instead of a wrong answer.
+pub trait Total: Sized {
+    /// The type of the finished total
+    type Answer;
+
+    /// The total of no values
+    fn zero() -> Self;
+
+    /// Add two totals together
+    fn add(self, other: Self) -> Self;
+
+    /// The finished total, or `None` if it overflowed
+    fn value(self) -> Option<Self::Answer>;
+
+    /// The finished total, failing with an overflow error if it overflowed
+    fn answer(self) -> Result<Self::Answer, ParseError>;
+}
+
+/// A total that can start from the value of a line
+pub trait FromValue<V>: Total {
+    /// The total of the value of a single line
+    fn of(value: V) -> Self;
+
+    /// Add up the values of a number of lines
+    fn sum(values: impl IntoIterator<Item = V>) -> Self {
+        values.into_iter().fold(Self::zero(), |total, value| total.add(Self::of(value)))
+    }
+}
+
+// The big integers add up like they always do, since they never overflow
+macro_rules! unchecked {
+    ($($t:ty),*) => {$(
+        impl Total for $t {
+            type Answer = $t;
+
+            fn zero() -> $t {
+                <$t>::default()
+            }
+
+            fn add(self, other: $t) -> $t {
+                self + other
+            }
+
+            fn value(self) -> Option<$t> {
+                Some(self)
+            }
+
+            fn answer(self) -> Result<$t, ParseError> {
+                Ok(self)
+            }
+        }
+
+        impl<V: Into<$t>> FromValue<V> for $t {
+            fn of(value: V) -> $t {
+                value.into()
+            }
+        }
+    )*};
+}
+
+unchecked!(BigUint, BigInt);
+
+/// A total in a primitive integer that remembers whether it overflowed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Checked<T> {
+    sum: Option<T>,
+}
+
+macro_rules! checked {
+    ($($t:ty),*) => {$(
+        impl Total for Checked<$t> {
+            type Answer = $t;
+
+            fn zero() -> Checked<$t> {
+                Checked { sum: Some(0) }
+            }
+
+            fn add(self, other: Checked<$t>) -> Checked<$t> {
+                Checked { sum: self.sum.zip(other.sum).and_then(|(a, b)| a.checked_add(b)) }
+            }
+
+            fn value(self) -> Option<$t> {
+                self.sum
+            }
+
+            fn answer(self) -> Result<$t, ParseError> {
+                self.sum.ok_or_else(|| ParseError::overflow(concat!("a total that fits in ", stringify!($t))))
+            }
+        }
+
+        impl<V: TryInto<$t>> FromValue<V> for Checked<$t> {
+            fn of(value: V) -> Checked<$t> {
+                Checked { sum: value.try_into().ok() }
+            }
+        }
+    )*};
+}
+
+checked!(u32, u64, usize, i64, u128, i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn native() {
+        assert_eq!(Checked::<u32>::sum([1_u32, 2, 3]).answer(), Ok(6));
+        assert_eq!(Checked::<i64>::sum([0_i64; 0]).value(), Some(0));
+        assert_eq!(Checked::<u32>::sum([u32::MAX, 1]).answer().unwrap_err().to_string(), "the answer overflowed, expected a total that fits in u32");
+        assert_eq!(Checked::<u32>::sum([u64::from(u32::MAX) + 1]).value(), None);
+    }
+
+    #[test]
+    fn checked() {
+        assert_eq!(Checked::<u128>::sum([u64::MAX, u64::MAX]).answer(), Ok(2 * u128::from(u64::MAX)));
+        assert_eq!(Checked::<i128>::sum([-5_i64, 3]).value(), Some(-2));
+        assert_eq!(Checked::<u128>::sum([-1_i64]).value(), None);
+
+        let overflow = Checked::<u128>::sum([u128::MAX, 0, 1]);
+        assert_eq!(overflow.value(), None);
+        assert_eq!(overflow.answer().unwrap_err().to_string(), "the answer overflowed, expected a total that fits in u128");
+        assert_eq!(overflow.add(Checked::zero()).value(), None);
+    }
+
+    #[test]
+    fn big() {
+        let total = BigUint::sum([u64::MAX, u64::MAX, u64::MAX]);
+        assert_eq!(total.answer(), Ok(BigUint::from(u64::MAX) * 3_u32));
+    }
+}
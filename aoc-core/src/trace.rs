@@ -0,0 +1,266 @@
+use std::cell::Cell;
+use std::collections::{BTreeMap, HashSet};
+use std::env;
+use std::fmt;
+use std::fs::File;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, ThreadId};
+use std::time::{Duration, Instant};
+
+use tracing::span::Id;
+use tracing::Subscriber;
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{Layer, Registry};
+
+/// Environment variable that enables tracing, with the same values as [Mode]
+pub const TRACE_VAR: &str = "AOC_TRACE";
+
+/// What to do with the spans the solvers record
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// Print how much time was spent in every kind of span, on how many threads
+    Phases,
+    /// Write the spans of every thread to a Chrome trace file, viewable in `chrome://tracing` or Perfetto
+    Chrome(PathBuf),
+}
+
+impl FromStr for Mode {
+    type Err = String;
+
+    fn from_str(mode: &str) -> Result<Mode, String> {
+        match mode.split_once('=') {
+            None if mode == "phases" => Ok(Mode::Phases),
+            None if mode == "chrome" => Ok(Mode::Chrome("trace.json".into())),
+            Some(("chrome", path)) if !path.is_empty() => Ok(Mode::Chrome(path.into())),
+            _ => Err(format!("Unknown trace mode {}, expected phases, chrome or chrome=path", mode)),
+        }
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mode::Phases => write!(f, "phases"),
+            Mode::Chrome(path) => write!(f, "chrome={}", path.display()),
+        }
+    }
+}
+
+/// Keeps the subscriber installed by [init] alive, printing the breakdown or finishing the trace file when dropped
+#[must_use = "the breakdown is printed or the trace is written when the guard is dropped"]
+pub struct Guard {
+    kind: Option<GuardKind>,
+}
+
+enum GuardKind {
+    Phases(Arc<Mutex<BTreeMap<&'static str, Phase>>>),
+    // Only held to flush the trace file when dropped
+    Chrome(#[allow(dead_code)] FlushGuard),
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        if let Some(GuardKind::Phases(phases)) = &self.kind {
+            eprint!("{}", breakdown(&phases.lock().unwrap()));
+        }
+    }
+}
+
+/// Install a global subscriber that records the [parse time](parse_time), and handles the spans for the mode if one is given
+pub fn init(mode: Option<&Mode>) -> Result<Guard, String> {
+    let subscriber = Registry::default().with(ParseLayer);
+    let (subscriber, kind): (Box<dyn Subscriber + Send + Sync>, Option<GuardKind>) = match mode {
+        None => (Box::new(subscriber), None),
+        Some(Mode::Phases) => {
+            let phases = Arc::new(Mutex::new(BTreeMap::new()));
+            let layer = PhaseLayer { phases: Arc::clone(&phases) };
+            (Box::new(subscriber.with(layer)), Some(GuardKind::Phases(phases)))
+        }
+        Some(Mode::Chrome(path)) => {
+            let file = File::create(path).map_err(|err| format!("Could not create trace file {}: {}", path.display(), err))?;
+            let (layer, guard) = ChromeLayerBuilder::new()
+                .writer(file)
+                .include_args(true)
+                .build();
+            (Box::new(subscriber.with(layer)), Some(GuardKind::Chrome(guard)))
+        }
+    };
+
+    tracing::subscriber::set_global_default(subscriber).map_err(|err| err.to_string())?;
+    Ok(Guard { kind })
+}
+
+/// Install a global subscriber with the mode the `AOC_TRACE` environment variable selects, if any
+pub fn from_env() -> Result<Guard, String> {
+    let mode = env::var(TRACE_VAR).ok()
+        .map(|mode| mode.parse())
+        .transpose()?;
+    init(mode.as_ref())
+}
+
+thread_local! {
+    // Wall time the thread spent in spans named `parse` during the measurement it is running, and how many of them it is in
+    static PARSE_TIME: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+    static PARSE_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Run a closure and measure the wall time the calling thread spent in spans named `parse` meanwhile,
+/// which is only recorded once [init] installed a subscriber
+///
+/// Parse spans on other threads, such as the workers of an executor, are left out since the calling thread waits for them
+/// in its own parse span. A measurement that runs inside another one on the same thread leaves its time out of the outer one.
+pub fn parse_time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let outer = PARSE_TIME.replace(Duration::ZERO);
+    let result = f();
+    (result, PARSE_TIME.replace(outer))
+}
+
+// Adds the wall time of the outermost span named `parse` a thread is in to the parse time of that thread
+pub(crate) struct ParseLayer;
+
+// When the current thread entered its outermost `parse` span
+struct ParseEntered(Instant);
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for ParseLayer {
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id).filter(|span| span.name() == "parse") {
+            if PARSE_DEPTH.replace(PARSE_DEPTH.get() + 1) == 0 {
+                span.extensions_mut().insert(ParseEntered(Instant::now()));
+            }
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id).filter(|span| span.name() == "parse") else {
+            return;
+        };
+        PARSE_DEPTH.set(PARSE_DEPTH.get().saturating_sub(1));
+        let entered = span.extensions_mut().remove();
+        if let Some(ParseEntered(start)) = entered {
+            PARSE_TIME.set(PARSE_TIME.get() + start.elapsed());
+        }
+    }
+}
+
+/// Time spent in all spans with the same name
+#[derive(Clone, Debug, Default)]
+struct Phase {
+    spans: usize,
+    busy: Duration,
+    threads: HashSet<ThreadId>,
+}
+
+// Records the time between entering and exiting every span, grouped by the name of the span
+struct PhaseLayer {
+    phases: Arc<Mutex<BTreeMap<&'static str, Phase>>>,
+}
+
+// When the current thread entered a span
+struct Entered(Instant);
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for PhaseLayer {
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(Entered(Instant::now()));
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let Some(Entered(start)) = span.extensions_mut().remove() else {
+            return;
+        };
+
+        let mut phases = self.phases.lock().unwrap();
+        let phase = phases.entry(span.name()).or_default();
+        phase.busy += start.elapsed();
+        phase.threads.insert(thread::current().id());
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(&id) {
+            self.phases.lock().unwrap().entry(span.name()).or_default().spans += 1;
+        }
+    }
+}
+
+// Render a table of the phases, where the time of nested phases is also counted in the phases around them
+fn breakdown(phases: &BTreeMap<&'static str, Phase>) -> String {
+    let mut table = format!("{:<10} {:>8} {:>8} {:>12}\n", "phase", "spans", "threads", "busy");
+    for (name, phase) in phases {
+        table += &format!("{:<10} {:>8} {:>8} {:>12}\n", name, phase.spans, phase.threads.len(), format!("{:.2?}", phase.busy));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_mode() {
+        assert_eq!("phases".parse(), Ok(Mode::Phases));
+        assert_eq!("chrome".parse(), Ok(Mode::Chrome("trace.json".into())));
+        assert_eq!("chrome=out/day8.json".parse(), Ok(Mode::Chrome("out/day8.json".into())));
+        assert!("chrome=".parse::<Mode>().is_err());
+        assert!("flame".parse::<Mode>().is_err());
+    }
+
+    #[test]
+    fn parse_time_is_recorded() {
+        let subscriber = Registry::default().with(ParseLayer);
+        let ((), parsed) = tracing::subscriber::with_default(subscriber, || parse_time(|| {
+            tracing::info_span!("solve").in_scope(|| {
+                tracing::info_span!("parse").in_scope(|| {
+                    // Nested parse spans and parse spans on other threads are already part of the outermost one
+                    tracing::info_span!("parse").in_scope(|| thread::sleep(Duration::from_millis(2)));
+                    thread::spawn(|| tracing::info_span!("parse").in_scope(|| thread::sleep(Duration::from_millis(2)))).join().unwrap();
+                });
+                thread::sleep(Duration::from_millis(50));
+            });
+        }));
+
+        // Only the time in the parse span counts
+        assert!(parsed >= Duration::from_millis(4) && parsed < Duration::from_millis(50), "{:?}", parsed);
+    }
+
+    #[test]
+    fn nested_parse_times() {
+        let subscriber = Registry::default().with(ParseLayer);
+        tracing::subscriber::with_default(subscriber, || {
+            // The inner measurement is left out of the outer one
+            let (inner, outer) = parse_time(|| {
+                let ((), inner) = parse_time(|| tracing::info_span!("parse").in_scope(|| thread::sleep(Duration::from_millis(20))));
+                tracing::info_span!("parse").in_scope(|| thread::sleep(Duration::from_millis(2)));
+                inner
+            });
+            assert!(inner >= Duration::from_millis(20), "{:?}", inner);
+            assert!(outer >= Duration::from_millis(2) && outer < Duration::from_millis(20), "{:?}", outer);
+        });
+    }
+
+    #[test]
+    fn phases_are_recorded() {
+        let phases = Arc::new(Mutex::new(BTreeMap::new()));
+        let subscriber = Registry::default().with(PhaseLayer { phases: Arc::clone(&phases) });
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!("solve").in_scope(|| {
+                for _ in 0..3 {
+                    tracing::info_span!("parse").in_scope(|| {});
+                }
+            });
+        });
+
+        let phases = phases.lock().unwrap();
+        assert_eq!(phases.keys().copied().collect::<Vec<_>>(), ["parse", "solve"]);
+        assert_eq!(phases["parse"].spans, 3);
+        assert_eq!(phases["solve"].threads.len(), 1);
+        assert!(breakdown(&phases).lines().nth(1).unwrap().starts_with("parse"));
+    }
+}
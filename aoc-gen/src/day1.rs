@@ -0,0 +1,33 @@
+use rand::seq::IndexedRandom;
+use rand::Rng as _;
+
+use crate::Rng;
+
+/// Number of calibration lines
+pub const SIZE: &str = "lines";
+
+// Digits spelled out, which part 2 also counts as digits
+const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+/// Generate calibration lines of letters, digits and spelled out digits, with at least one digit on every line
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        for _ in 0..rng.random_range(1..=8) {
+            match rng.random_range(0..3) {
+                0 => line.push(char::from(b'0' + rng.random_range(1..=9))),
+                1 => line += WORDS.choose(rng).unwrap(),
+                _ => line.extend((0..rng.random_range(1..=5)).map(|_| char::from(rng.random_range(b'a'..=b'z')))),
+            }
+        }
+
+        // Insert a digit at a random position so part 1 always finds one
+        let position = rng.random_range(0..=line.len());
+        line.insert(position, char::from(b'0' + rng.random_range(1..=9)));
+
+        input += &line;
+        input.push('\n');
+    }
+    input
+}
@@ -0,0 +1,126 @@
+use rand::seq::IndexedRandom;
+use rand::Rng as _;
+
+use crate::Rng;
+
+/// Width and height of the square field, at least 2
+pub const SIZE: &str = "width and height";
+
+// Pipes other than the loop, where ground is about as common as every pipe
+const JUNK: [u8; 12] = *b"|-LJ7F......";
+
+/// Generate a field with a single loop through the start tile, surrounded by pipes that are not part of it
+///
+/// The loop is the boundary of a shape with a contiguous run of cells in every column, where runs of neighbouring
+/// columns overlap, so it never touches itself.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+
+    // Pick the runs of cells between the tiles of the columns, where every run overlaps the run before it
+    let first = rng.random_range(0..size - 1);
+    let last = rng.random_range(first..size - 1);
+    let mut runs: Vec<(usize, usize)> = Vec::with_capacity(last - first + 1);
+    for _ in first..=last {
+        let (top, bottom) = runs.last().copied().unwrap_or((0, size - 2));
+        let shared = rng.random_range(top..=bottom);
+        runs.push((rng.random_range(0..=shared), rng.random_range(shared..size - 1)));
+    }
+
+    // Walk the corners of the shape clockwise, starting at the bottom left, along the top and back along the bottom
+    let mut corners = vec![(first, runs[0].1 + 1), (first, runs[0].0)];
+    for (column, &(top, _)) in (first..).zip(&runs) {
+        corners.push((column + 1, top));
+        if let Some(&(next_top, _)) = runs.get(column + 1 - first) {
+            corners.push((column + 1, next_top));
+        }
+    }
+    for (column, &(_, bottom)) in (first..last + 1).zip(&runs).rev() {
+        corners.push((column + 1, bottom + 1));
+        corners.push((column, bottom + 1));
+    }
+
+    // Follow the edges between the corners tile by tile
+    let mut path: Vec<(usize, usize)> = Vec::new();
+    for pair in corners.windows(2) {
+        let (mut x, mut y) = pair[0];
+        while (x, y) != pair[1] {
+            path.push((x, y));
+            x = if x < pair[1].0 { x + 1 } else if x > pair[1].0 { x - 1 } else { x };
+            y = if y < pair[1].1 { y + 1 } else if y > pair[1].1 { y - 1 } else { y };
+        }
+    }
+
+    // Connect every tile of the loop to the tiles before and after it, swapping the axes for half of the fields
+    let transpose = rng.random_bool(0.5);
+    let mut field = vec![vec![b'.'; size]; size];
+    for (i, &(x, y)) in path.iter().enumerate() {
+        let before = path[(i + path.len() - 1) % path.len()];
+        let after = path[(i + 1) % path.len()];
+        let pipe = pipe(direction((x, y), before), direction((x, y), after));
+        if transpose {
+            field[x][y] = transposed(pipe);
+        } else {
+            field[y][x] = pipe;
+        }
+    }
+
+    // Fill the other tiles with pipes, except for pipes that would connect to the start tile
+    let (x, y) = path[rng.random_range(0..path.len())];
+    let start = if transpose { (y, x) } else { (x, y) };
+    for (y, row) in field.iter_mut().enumerate() {
+        for (x, tile) in row.iter_mut().enumerate() {
+            if *tile == b'.' {
+                let pipe = *JUNK.choose(rng).unwrap();
+                let connects = connections(pipe).into_iter()
+                    .any(|(dx, dy)| (x.checked_add_signed(dx), y.checked_add_signed(dy)) == (Some(start.0), Some(start.1)));
+                *tile = if connects { b'.' } else { pipe };
+            }
+        }
+    }
+    field[start.1][start.0] = b'S';
+
+    let mut input = String::new();
+    for row in field {
+        input.extend(row.into_iter().map(char::from));
+        input.push('\n');
+    }
+    input
+}
+
+// Direction from a tile to a neighbouring tile
+fn direction(from: (usize, usize), to: (usize, usize)) -> (isize, isize) {
+    (to.0 as isize - from.0 as isize, to.1 as isize - from.1 as isize)
+}
+
+// Directions a pipe connects to, where up is a negative direction
+fn connections(pipe: u8) -> Vec<(isize, isize)> {
+    match pipe {
+        b'|' => vec![(0, -1), (0, 1)],
+        b'-' => vec![(-1, 0), (1, 0)],
+        b'L' => vec![(0, -1), (1, 0)],
+        b'J' => vec![(0, -1), (-1, 0)],
+        b'7' => vec![(0, 1), (-1, 0)],
+        b'F' => vec![(0, 1), (1, 0)],
+        _ => vec![],
+    }
+}
+
+// Pipe that connects to both directions
+fn pipe(a: (isize, isize), b: (isize, isize)) -> u8 {
+    *b"|-LJ7F".iter()
+        .find(|&&pipe| connections(pipe).contains(&a) && connections(pipe).contains(&b))
+        .unwrap()
+}
+
+// Pipe mirrored along the diagonal, which swaps the axes
+fn transposed(pipe: u8) -> u8 {
+    match pipe {
+        b'|' => b'-',
+        b'-' => b'|',
+        b'J' => b'J',
+        b'F' => b'F',
+        b'L' => b'7',
+        b'7' => b'L',
+        _ => pipe,
+    }
+}
@@ -0,0 +1,22 @@
+use rand::Rng as _;
+
+use crate::Rng;
+
+/// Width and height of the square image
+pub const SIZE: &str = "width and height";
+
+/// Generate an image of sparse galaxies, where some rows and columns are left empty so the universe expands
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.random_bool(0.05)).collect();
+    let empty_columns: Vec<bool> = (0..size).map(|_| rng.random_bool(0.05)).collect();
+
+    let mut input = String::new();
+    for &empty_row in &empty_rows {
+        for &empty_column in &empty_columns {
+            let galaxy = !empty_row && !empty_column && rng.random_bool(0.03);
+            input.push(if galaxy { '#' } else { '.' });
+        }
+        input.push('\n');
+    }
+    input
+}
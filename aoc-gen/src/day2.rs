@@ -0,0 +1,27 @@
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+use crate::Rng;
+
+/// Number of games
+pub const SIZE: &str = "games";
+
+/// Generate games of up to 6 sets of cubes, where every set shows between 1 and 20 cubes of up to 3 colors
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for game in 1..=size {
+        let sets: Vec<String> = (0..rng.random_range(1..=6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                colors.shuffle(rng);
+                colors[..rng.random_range(1..=3)].iter()
+                    .map(|color| format!("{} {}", rng.random_range(1..=20), color))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+
+        input += &format!("Game {}: {}\n", game, sets.join("; "));
+    }
+    input
+}
@@ -0,0 +1,37 @@
+use rand::seq::IndexedRandom;
+use rand::Rng as _;
+
+use crate::Rng;
+
+/// Width and height of the square engine schematic
+pub const SIZE: &str = "width and height";
+
+// Symbols that mark part numbers, where `*` can also be a gear
+const SYMBOLS: [u8; 10] = *b"#$%&*+-/=@";
+
+/// Generate an engine schematic of numbers of 1 to 3 digits and symbols, where about half of the symbols are `*`
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut row = Vec::with_capacity(size);
+        while row.len() < size {
+            let length = rng.random_range(1..=3);
+            if row.len() + length <= size && rng.random_bool(0.15) {
+                // Numbers do not start with a zero and are always followed by something else than a digit
+                row.push(rng.random_range(b'1'..=b'9'));
+                row.extend((1..length).map(|_| rng.random_range(b'0'..=b'9')));
+                if row.len() < size {
+                    row.push(b'.');
+                }
+            } else if rng.random_bool(0.08) {
+                row.push(if rng.random_bool(0.5) { b'*' } else { *SYMBOLS.choose(rng).unwrap() });
+            } else {
+                row.push(b'.');
+            }
+        }
+
+        input.extend(row.into_iter().map(char::from));
+        input.push('\n');
+    }
+    input
+}
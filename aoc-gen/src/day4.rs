@@ -0,0 +1,39 @@
+use rand::seq::index;
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+use crate::Rng;
+
+/// Number of scratchcards
+pub const SIZE: &str = "cards";
+
+// Number of winning numbers and numbers you have on every card, like the puzzle input
+const WINNING: usize = 10;
+const HAVE: usize = 25;
+
+// Cards only win copies of cards within their block, which keeps the number of copies of part 2 below 2^BLOCK
+const BLOCK: usize = 10;
+
+/// Generate scratchcards with 10 winning numbers and 25 numbers you have, all from 1 to 99
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.to_string().len().max(3);
+
+    let mut input = String::new();
+    for card in 1..=size {
+        // Draw distinct numbers, of which the first are winning and the next are the matching numbers you have
+        let numbers: Vec<usize> = index::sample(rng, 99, WINNING + HAVE).into_iter().map(|number| number + 1).collect();
+        let matches = rng.random_range(0..=(BLOCK - 1 - (card - 1) % BLOCK).min(WINNING));
+
+        let mut winning = numbers[..WINNING].to_vec();
+        let mut have: Vec<usize> = numbers[..matches].iter()
+            .chain(&numbers[WINNING..WINNING + HAVE - matches])
+            .copied()
+            .collect();
+        winning.shuffle(rng);
+        have.shuffle(rng);
+
+        let format = |numbers: &[usize]| numbers.iter().map(|number| format!("{:>2}", number)).collect::<Vec<_>>().join(" ");
+        input += &format!("Card {:>width$}: {} | {}\n", card, format(&winning), format(&have), width = width);
+    }
+    input
+}
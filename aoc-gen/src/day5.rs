@@ -0,0 +1,70 @@
+use std::collections::BTreeSet;
+
+use rand::seq::SliceRandom;
+use rand::Rng as _;
+
+use crate::Rng;
+
+/// Number of seed ranges and number of ranges in every map, at least 1
+pub const SIZE: &str = "seed ranges and ranges per map";
+
+// All numbers are below 2^32 like in the puzzle input
+const SPACE: u64 = 1 << 32;
+
+const MAPS: [&str; 7] = ["seed-to-soil", "soil-to-fertilizer", "fertilizer-to-water", "water-to-light", "light-to-temperature", "temperature-to-humidity", "humidity-to-location"];
+
+/// Generate an almanac of disjoint seed ranges and 7 maps, each mapping disjoint source ranges to disjoint destination ranges
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+
+    let mut seeds = disjoint_ranges(rng, size);
+    seeds.shuffle(rng);
+    let seeds: Vec<String> = seeds.iter().map(|(start, length)| format!("{} {}", start, length)).collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for map in MAPS {
+        input += &format!("\n{} map:\n", map);
+
+        // Lay out the same ranges in another order with other gaps in between for the destinations
+        let sources = disjoint_ranges(rng, size);
+        let mut order: Vec<usize> = (0..size).collect();
+        order.shuffle(rng);
+        let free = SPACE - sources.iter().map(|(_, length)| length).sum::<u64>();
+        let mut gaps: Vec<u64> = (0..size).map(|_| rng.random_range(0..=free)).collect();
+        gaps.sort_unstable();
+
+        let mut destinations = vec![0; size];
+        let mut start = 0;
+        let mut previous_gap = 0;
+        for (&i, gap) in order.iter().zip(gaps) {
+            start += gap - previous_gap;
+            previous_gap = gap;
+            destinations[i] = start;
+            start += sources[i].1;
+        }
+
+        let mut lines: Vec<String> = sources.iter()
+            .zip(destinations)
+            .map(|(&(source, length), destination)| format!("{} {} {}", destination, source, length))
+            .collect();
+        lines.shuffle(rng);
+        for line in lines {
+            input += &line;
+            input.push('\n');
+        }
+    }
+    input
+}
+
+// Pick disjoint non-empty ranges as (start, length) ordered by start, by pairing up sorted distinct points
+fn disjoint_ranges(rng: &mut Rng, count: usize) -> Vec<(u64, u64)> {
+    let mut points = BTreeSet::new();
+    while points.len() < 2 * count {
+        points.insert(rng.random_range(0..SPACE));
+    }
+
+    let points: Vec<u64> = points.into_iter().collect();
+    points.chunks(2)
+        .map(|pair| (pair[0], pair[1] - pair[0]))
+        .collect()
+}
@@ -0,0 +1,26 @@
+use rand::Rng as _;
+
+use crate::Rng;
+
+/// Number of races, at least 1, where the concatenated record of part 2 no longer fits in 64 bits beyond 5 races
+pub const SIZE: &str = "races";
+
+/// Generate races of 2 digit times with a record that can be beaten, also when the digits are concatenated for part 2
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut times = Vec::new();
+    let mut distances = Vec::new();
+    for race in 0..size.max(1) {
+        // The record is below the best distance of (time / 2) * (time - time / 2)
+        // The first race starts with a 2 and has a record of at most 2 digits, which keeps the concatenated record
+        // of part 2 below 10^(2n - 2), the square of the concatenated times divided by 4 for times of n digits
+        let time: u64 = rng.random_range(if race == 0 { 20..=29 } else { 10..=99 });
+        let best = time / 2 * (time - time / 2);
+        let distance = rng.random_range(1..if race == 0 { best.min(100) } else { best });
+
+        times.push(time);
+        distances.push(distance);
+    }
+
+    let format = |label: &str, numbers: &[u64]| numbers.iter().fold(format!("{:<9}", label), |line, number| line + &format!(" {:>6}", number));
+    format!("{}\n{}\n", format("Time:", &times), format("Distance:", &distances))
+}
@@ -0,0 +1,29 @@
+use std::collections::HashSet;
+
+use rand::seq::IndexedRandom;
+use rand::Rng as _;
+
+use crate::Rng;
+
+/// Number of hands, at most 13^5
+pub const SIZE: &str = "hands";
+
+const CARDS: [u8; 13] = *b"23456789TJQKA";
+
+/// Generate distinct hands of 5 cards with bids from 1 to 1000
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.min(CARDS.len().pow(5));
+
+    let mut hands = HashSet::new();
+    let mut input = String::new();
+    while hands.len() < size {
+        // Draw the hand from a small selection of cards, so every type of hand is common
+        let cards: Vec<u8> = (0..rng.random_range(1..=5)).map(|_| *CARDS.choose(rng).unwrap()).collect();
+        let hand: String = (0..5).map(|_| char::from(*cards.choose(rng).unwrap())).collect();
+
+        if hands.insert(hand.clone()) {
+            input += &format!("{} {}\n", hand, rng.random_range(1..=1000));
+        }
+    }
+    input
+}
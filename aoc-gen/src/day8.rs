@@ -0,0 +1,105 @@
+use rand::seq::{index, IndexedRandom, SliceRandom};
+use rand::Rng as _;
+
+use crate::Rng;
+
+/// Number of nodes, between 3 and 16000
+pub const SIZE: &str = "nodes";
+
+// Primes to pick the number of rounds of steps in the cycle of every ghost from
+const PRIMES: [usize; 22] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79];
+
+// Letters of the node names, where the last letter of inner nodes is neither A nor Z
+const LETTERS: usize = 26;
+const INNER_LETTERS: usize = LETTERS - 2;
+
+/// Generate a network with up to 6 ghosts, starting with AAA, that walk a cycle back to a node of their path
+///
+/// Every ghost reaches its end node after a number of steps that is the number of steps times a distinct prime. Half
+/// of the ghosts then continue exactly like from their start node, so they reach the end node again at every multiple
+/// of their first step count. The others loop back to a later round of their path, which makes their cycle shorter
+/// than the steps to their first arrival, so part 2 is not simply the least common multiple of these step counts.
+/// The number of rounds of all cycles are coprime, so the ghosts always end up at their end nodes together.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(3, 16000);
+
+    // Split the nodes over the ghosts and pick the number of steps so every ghost can walk a multiple of them
+    let budget = size / (size / 200).clamp(1, 6);
+    let step_count = (budget / 80).max(1);
+    let eligible: Vec<usize> = PRIMES.into_iter().filter(|prime| step_count * prime < budget).collect();
+    let rounds: Vec<usize> = eligible.choose_multiple(rng, (size / budget).min(eligible.len())).copied().collect();
+    let steps: Vec<usize> = (0..step_count).map(|_| rng.random_range(0..2)).collect();
+
+    // Pick the number of rounds in the cycle of every ghost, which are coprime to the cycles of all other ghosts
+    let mut cycles: Vec<usize> = Vec::with_capacity(rounds.len());
+    for (ghost, &round_count) in rounds.iter().enumerate() {
+        let coprime = |cycle: usize| cycles.iter().chain(&rounds[ghost + 1..]).all(|&other| gcd(cycle, other) == 1);
+        let shorter: Vec<usize> = (1..round_count).filter(|&cycle| coprime(cycle)).collect();
+        let cycle = match shorter.choose(rng) {
+            Some(&cycle) if rng.random_bool(0.5) => cycle,
+            _ => round_count,
+        };
+        cycles.push(cycle);
+    }
+
+    // Name the start and end nodes of every ghost and all other nodes
+    // The first ghost starts at AAA and ends at ZZZ, taking the first two letters from the back
+    let mut prefixes: Vec<usize> = index::sample(rng, LETTERS * LETTERS - 2, 2 * (rounds.len() - 1)).into_iter()
+        .map(|prefix| prefix + 1)
+        .collect();
+    prefixes.extend([LETTERS * LETTERS - 1, 0]);
+    let inner_count = size - 2 * rounds.len();
+    let inner: Vec<String> = index::sample(rng, LETTERS * LETTERS * INNER_LETTERS, inner_count).into_iter()
+        .map(|index| name(index / INNER_LETTERS, index % INNER_LETTERS + 1))
+        .collect();
+
+    // Walk the cycle of every ghost, leaving the other direction of every node to a random node
+    let mut nodes: Vec<(String, [String; 2])> = Vec::with_capacity(size);
+    let mut inner_nodes = inner.iter();
+    let random_node = |rng: &mut Rng| inner.choose(rng).unwrap().clone();
+    for (&round_count, &cycle) in rounds.iter().zip(&cycles) {
+        let start = name(prefixes.pop().unwrap(), 0);
+        let end = name(prefixes.pop().unwrap(), LETTERS - 1);
+        let length = step_count * round_count;
+
+        let path: Vec<String> = [start.clone()].into_iter()
+            .chain(inner_nodes.by_ref().take(length - 1).cloned())
+            .chain([end.clone()])
+            .collect();
+        for (position, node) in path.iter().enumerate().take(length) {
+            let mut directions = [random_node(rng), random_node(rng)];
+            directions[steps[position % step_count]] = path[position + 1].clone();
+            nodes.push((node.clone(), directions));
+        }
+
+        // The end node continues like the first node of the round its cycle starts at, since both are followed by the first step
+        let mut directions = [random_node(rng), random_node(rng)];
+        directions[steps[0]] = path[(round_count - cycle) * step_count + 1].clone();
+        nodes.push((end, directions));
+    }
+
+    // Fill the network with nodes that no ghost visits
+    for node in inner_nodes {
+        nodes.push((node.clone(), [random_node(rng), random_node(rng)]));
+    }
+
+    nodes.shuffle(rng);
+    let mut input: String = steps.iter().map(|&step| if step == 0 { 'L' } else { 'R' }).collect();
+    input += "\n\n";
+    for (node, [left, right]) in nodes {
+        input += &format!("{} = ({}, {})\n", node, left, right);
+    }
+    input
+}
+
+// Greatest common divisor of two numbers
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+// Name of a node from the index of its first two letters and the index of its last letter
+fn name(prefix: usize, last: usize) -> String {
+    [prefix / LETTERS, prefix % LETTERS, last].into_iter()
+        .map(|letter| char::from(b'A' + letter as u8))
+        .collect()
+}
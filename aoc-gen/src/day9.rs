@@ -0,0 +1,36 @@
+use rand::Rng as _;
+
+use crate::Rng;
+
+/// Number of histories
+pub const SIZE: &str = "histories";
+
+// Number of values in every history, like the puzzle input
+const VALUES: i64 = 21;
+
+/// Generate histories of 21 values of a polynomial of degree at most 19, so the differences always reach all zeroes
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        // Pick the first value of every row of differences, which gives the values as sums of binomials
+        let degree = rng.random_range(0..VALUES - 1);
+        let differences: Vec<i64> = (0..=degree).map(|_| rng.random_range(-9..=9)).collect();
+
+        let values: Vec<String> = (0..VALUES)
+            .map(|x| differences.iter().zip(binomials(x)).map(|(difference, binomial)| difference * binomial).sum::<i64>())
+            .map(|value| value.to_string())
+            .collect();
+        input += &values.join(" ");
+        input.push('\n');
+    }
+    input
+}
+
+// Binomials x choose k for k = 0, 1, 2, ...
+fn binomials(x: i64) -> impl Iterator<Item = i64> {
+    (0..).scan(1, move |binomial, k| {
+        let current = *binomial;
+        *binomial = *binomial * (x - k) / (k + 1);
+        Some(current)
+    })
+}
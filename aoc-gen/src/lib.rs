@@ -0,0 +1,75 @@
+//! Generators of synthetic puzzle inputs for every day, for stress testing and benchmarking beyond the size of the personal inputs.
+//!
+//! Every generator takes a size, whose meaning depends on the day, and a seeded random number generator,
+//! so the same size and seed always generate the same input.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// Random number generator used by the generators, which generates the same numbers for a seed on every platform
+pub type Rng = ChaCha8Rng;
+
+/// A generator of inputs for both parts of a day
+pub struct Generator {
+    pub day: u32,
+    /// What the size of the generated input means for this day
+    pub size: &'static str,
+    /// Generate an input of the given size
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+/// Generators of all days ordered by day
+pub static GENERATORS: &[Generator] = &[
+    Generator { day: 1, size: day1::SIZE, generate: day1::generate },
+    Generator { day: 2, size: day2::SIZE, generate: day2::generate },
+    Generator { day: 3, size: day3::SIZE, generate: day3::generate },
+    Generator { day: 4, size: day4::SIZE, generate: day4::generate },
+    Generator { day: 5, size: day5::SIZE, generate: day5::generate },
+    Generator { day: 6, size: day6::SIZE, generate: day6::generate },
+    Generator { day: 7, size: day7::SIZE, generate: day7::generate },
+    Generator { day: 8, size: day8::SIZE, generate: day8::generate },
+    Generator { day: 9, size: day9::SIZE, generate: day9::generate },
+    Generator { day: 10, size: day10::SIZE, generate: day10::generate },
+    Generator { day: 11, size: day11::SIZE, generate: day11::generate },
+];
+
+/// Create the random number generator for a seed
+pub fn rng(seed: u64) -> Rng {
+    Rng::seed_from_u64(seed)
+}
+
+/// Generate an input for a day of the given size from a seed, if there is a generator for the day
+pub fn generate(day: u32, size: usize, seed: u64) -> Option<String> {
+    GENERATORS.iter()
+        .find(|generator| generator.day == day)
+        .map(|generator| (generator.generate)(&mut rng(seed), size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeds_are_reproducible() {
+        for generator in GENERATORS {
+            for size in [0, 1, 7, 50] {
+                let input = generate(generator.day, size, 42).unwrap();
+                assert_eq!(input, generate(generator.day, size, 42).unwrap(), "day {} size {}", generator.day, size);
+                assert!(input.is_empty() || input.ends_with('\n'), "day {} size {}", generator.day, size);
+            }
+            assert_ne!(generate(generator.day, 50, 1), generate(generator.day, 50, 2), "day {}", generator.day);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+/// Sum the first and last digit of every line
+pub fn part1(input: &str) -> u32 {
+    input.lines()
+        .map(|line| {
+            let digits: Vec<u32> = line.chars().filter_map(|c| c.to_digit(10)).collect();
+            10 * digits[0] + digits[digits.len() - 1]
+        })
+        .sum()
+}
+
+/// Sum the first and last digit of every line, reading a digit or spelled out digit at every position
+pub fn part2(input: &str) -> u32 {
+    input.lines()
+        .map(|line| {
+            let digits: Vec<u32> = (0..line.len())
+                .filter_map(|i| {
+                    let rest = &line[i..];
+                    rest.chars().next().unwrap().to_digit(10)
+                        .or_else(|| (1..).zip(WORDS).find(|(_, word)| rest.starts_with(word)).map(|(digit, _)| digit))
+                })
+                .collect();
+            10 * digits[0] + digits[digits.len() - 1]
+        })
+        .sum()
+}
@@ -0,0 +1,90 @@
+use std::collections::VecDeque;
+
+// Directions of up, down, left and right as row and column differences
+const DIRECTIONS: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+// Directions a tile connects to
+fn connections(tile: u8) -> Vec<(isize, isize)> {
+    match tile {
+        b'|' => vec![(-1, 0), (1, 0)],
+        b'-' => vec![(0, -1), (0, 1)],
+        b'L' => vec![(-1, 0), (0, 1)],
+        b'J' => vec![(-1, 0), (0, -1)],
+        b'7' => vec![(1, 0), (0, -1)],
+        b'F' => vec![(1, 0), (0, 1)],
+        _ => vec![],
+    }
+}
+
+// The field with the start replaced by the pipe connecting it to the two pipes that connect to it, and the loop in order
+fn field(input: &str) -> (Vec<Vec<u8>>, Vec<(usize, usize)>) {
+    let mut field: Vec<Vec<u8>> = input.lines().map(|line| line.as_bytes().to_vec()).collect();
+    let start = field.iter()
+        .enumerate()
+        .find_map(|(row, line)| line.iter().position(|&tile| tile == b'S').map(|column| (row, column)))
+        .unwrap();
+
+    let (height, width) = (field.len(), field[0].len());
+    let neighbour = |(row, column): (usize, usize), (dr, dc): (isize, isize)| {
+        let neighbour = (row.checked_add_signed(dr)?, column.checked_add_signed(dc)?);
+        (neighbour.0 < height && neighbour.1 < width).then_some(neighbour)
+    };
+    let connected: Vec<(isize, isize)> = DIRECTIONS.into_iter()
+        .filter(|&(dr, dc)| neighbour(start, (dr, dc)).is_some_and(|(row, column)| connections(field[row][column]).contains(&(-dr, -dc))))
+        .collect();
+    let pipe = *b"|-LJ7F".iter().find(|&&pipe| connections(pipe) == connected || connections(pipe).iter().rev().eq(&connected)).unwrap();
+    field[start.0][start.1] = pipe;
+
+    // Walk along the loop until returning to the start
+    let mut path = vec![start];
+    let mut direction = connected[0];
+    let mut position = start;
+    loop {
+        position = neighbour(position, direction).unwrap();
+        if position == start {
+            break;
+        }
+        path.push(position);
+        direction = connections(field[position.0][position.1]).into_iter().find(|&(dr, dc)| (dr, dc) != (-direction.0, -direction.1)).unwrap();
+    }
+    (field, path)
+}
+
+/// Count the steps to the furthest tile of the loop, which is halfway along it
+pub fn part1(input: &str) -> usize {
+    field(input).1.len() / 2
+}
+
+/// Count the tiles enclosed by the loop by drawing the loop three times larger and flooding everything outside of it
+pub fn part2(input: &str) -> usize {
+    let (field, path) = field(input);
+    let (height, width) = (field.len(), field[0].len());
+
+    // Draw every tile of the loop as its center and its connections, with a border to flood around the loop
+    let mut large = vec![vec![false; 3 * width + 2]; 3 * height + 2];
+    for &(row, column) in &path {
+        let (center_row, center_column) = (3 * row + 2, 3 * column + 2);
+        large[center_row][center_column] = true;
+        for (dr, dc) in connections(field[row][column]) {
+            large[center_row.wrapping_add_signed(dr)][center_column.wrapping_add_signed(dc)] = true;
+        }
+    }
+
+    let mut outside = vec![vec![false; 3 * width + 2]; 3 * height + 2];
+    let mut queue = VecDeque::from([(0usize, 0usize)]);
+    outside[0][0] = true;
+    while let Some((row, column)) = queue.pop_front() {
+        for (dr, dc) in DIRECTIONS {
+            let (Some(row), Some(column)) = (row.checked_add_signed(dr), column.checked_add_signed(dc)) else { continue };
+            if row < large.len() && column < large[0].len() && !large[row][column] && !outside[row][column] {
+                outside[row][column] = true;
+                queue.push_back((row, column));
+            }
+        }
+    }
+
+    (0..height)
+        .flat_map(|row| (0..width).map(move |column| (row, column)))
+        .filter(|&(row, column)| !path.contains(&(row, column)) && !outside[3 * row + 2][3 * column + 2])
+        .count()
+}
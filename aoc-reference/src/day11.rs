@@ -0,0 +1,29 @@
+// Sum the distances between all pairs of galaxies, where every empty row and column counts as the given number of rows or columns
+fn distances(input: &str, expansion: usize) -> usize {
+    let image: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+    let galaxies: Vec<(usize, usize)> = image.iter()
+        .enumerate()
+        .flat_map(|(row, line)| line.iter().enumerate().filter(|(_, &c)| c == b'#').map(move |(column, _)| (row, column)))
+        .collect();
+    let empty_row = |row: usize| image[row].iter().all(|&c| c == b'.');
+    let empty_column = |column: usize| image.iter().all(|line| line[column] == b'.');
+
+    let mut sum = 0;
+    for (i, &(row, column)) in galaxies.iter().enumerate() {
+        for &(other_row, other_column) in &galaxies[i + 1..] {
+            sum += (row.min(other_row)..row.max(other_row)).map(|row| if empty_row(row) { expansion } else { 1 }).sum::<usize>();
+            sum += (column.min(other_column)..column.max(other_column)).map(|column| if empty_column(column) { expansion } else { 1 }).sum::<usize>();
+        }
+    }
+    sum
+}
+
+/// Sum the distances between all pairs of galaxies, where every empty row and column is twice as large
+pub fn part1(input: &str) -> usize {
+    distances(input, 2)
+}
+
+/// Sum the distances between all pairs of galaxies, where every empty row and column is a million times as large
+pub fn part2(input: &str) -> usize {
+    distances(input, 1_000_000)
+}
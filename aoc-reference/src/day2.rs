@@ -0,0 +1,35 @@
+// Every draw of a game as the number of red, green and blue cubes
+fn games(input: &str) -> Vec<(u32, Vec<[u32; 3]>)> {
+    input.lines()
+        .map(|line| {
+            let (game, draws) = line.split_once(": ").unwrap();
+            let draws = draws.split("; ")
+                .map(|draw| {
+                    let mut cubes = [0; 3];
+                    for cube in draw.split(", ") {
+                        let (count, color) = cube.split_once(' ').unwrap();
+                        let index = ["red", "green", "blue"].iter().position(|&name| name == color).unwrap();
+                        cubes[index] += count.parse::<u32>().unwrap();
+                    }
+                    cubes
+                })
+                .collect();
+            (game.strip_prefix("Game ").unwrap().parse().unwrap(), draws)
+        })
+        .collect()
+}
+
+/// Sum the numbers of the games where no draw shows more than 12 red, 13 green or 14 blue cubes
+pub fn part1(input: &str) -> u32 {
+    games(input).into_iter()
+        .filter(|(_, draws)| draws.iter().all(|draw| draw[0] <= 12 && draw[1] <= 13 && draw[2] <= 14))
+        .map(|(game, _)| game)
+        .sum()
+}
+
+/// Sum the product of the most cubes of every color drawn in every game
+pub fn part2(input: &str) -> u32 {
+    games(input).into_iter()
+        .map(|(_, draws)| (0..3).map(|color| draws.iter().map(|draw| draw[color]).max().unwrap()).product::<u32>())
+        .sum()
+}
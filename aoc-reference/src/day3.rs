@@ -0,0 +1,61 @@
+// Every number in the schematic as its value, row and the range of its columns
+fn numbers(grid: &[Vec<u8>]) -> Vec<(u32, usize, usize, usize)> {
+    let mut numbers = Vec::new();
+    for (row, line) in grid.iter().enumerate() {
+        let mut column = 0;
+        while column < line.len() {
+            let start = column;
+            while column < line.len() && line[column].is_ascii_digit() {
+                column += 1;
+            }
+            if column > start {
+                let value = std::str::from_utf8(&line[start..column]).unwrap().parse().unwrap();
+                numbers.push((value, row, start, column - 1));
+            } else {
+                column += 1;
+            }
+        }
+    }
+    numbers
+}
+
+// Whether a cell touches a number, including diagonally
+fn touches(row: usize, column: usize, number: &(u32, usize, usize, usize)) -> bool {
+    let &(_, number_row, start, end) = number;
+    row.abs_diff(number_row) <= 1 && column + 1 >= start && column <= end + 1
+}
+
+fn grid(input: &str) -> Vec<Vec<u8>> {
+    input.lines().map(|line| line.as_bytes().to_vec()).collect()
+}
+
+// Every symbol in the schematic as its character, row and column
+fn symbols(grid: &[Vec<u8>]) -> Vec<(u8, usize, usize)> {
+    grid.iter()
+        .enumerate()
+        .flat_map(|(row, line)| line.iter().enumerate().map(move |(column, &c)| (c, row, column)))
+        .filter(|&(c, _, _)| c != b'.' && !c.is_ascii_digit())
+        .collect()
+}
+
+/// Sum the numbers that touch any symbol
+pub fn part1(input: &str) -> u32 {
+    let grid = grid(input);
+    let symbols = symbols(&grid);
+    numbers(&grid).iter()
+        .filter(|number| symbols.iter().any(|&(_, row, column)| touches(row, column, number)))
+        .map(|number| number.0)
+        .sum()
+}
+
+/// Sum the products of the numbers touching every `*` that touches exactly two numbers
+pub fn part2(input: &str) -> u32 {
+    let grid = grid(input);
+    let numbers = numbers(&grid);
+    symbols(&grid).into_iter()
+        .filter(|&(c, _, _)| c == b'*')
+        .map(|(_, row, column)| numbers.iter().filter(|number| touches(row, column, number)).map(|number| number.0).collect::<Vec<_>>())
+        .filter(|touching| touching.len() == 2)
+        .map(|touching| touching[0] * touching[1])
+        .sum()
+}
@@ -0,0 +1,30 @@
+// The number of winning numbers you have on every card
+fn matches(input: &str) -> Vec<usize> {
+    input.lines()
+        .map(|line| {
+            let (_, numbers) = line.split_once(':').unwrap();
+            let (winning, have) = numbers.split_once('|').unwrap();
+            let winning: Vec<&str> = winning.split_whitespace().collect();
+            have.split_whitespace().filter(|number| winning.contains(number)).count()
+        })
+        .collect()
+}
+
+/// Sum the scores of the cards, which double for every match after the first
+pub fn part1(input: &str) -> u32 {
+    matches(input).into_iter()
+        .map(|matches| if matches == 0 { 0 } else { 2u32.pow(matches as u32 - 1) })
+        .sum()
+}
+
+/// Scratch every card one by one, adding copies of the next cards for every match, and count all cards scratched
+pub fn part2(input: &str) -> usize {
+    let matches = matches(input);
+    let mut pile: Vec<usize> = (0..matches.len()).collect();
+    let mut scratched = 0;
+    while let Some(card) = pile.pop() {
+        scratched += 1;
+        pile.extend(card + 1..(card + 1 + matches[card]).min(matches.len()));
+    }
+    scratched
+}
@@ -0,0 +1,58 @@
+// The seed numbers and every map as its lines of destination start, source start and length
+fn almanac(input: &str) -> (Vec<u64>, Vec<Vec<[u64; 3]>>) {
+    let mut blocks = input.split("\n\n");
+    let seeds = blocks.next().unwrap()
+        .strip_prefix("seeds:").unwrap()
+        .split_whitespace()
+        .map(|number| number.parse().unwrap())
+        .collect();
+    let maps = blocks
+        .map(|block| block.lines()
+            .skip(1)
+            .map(|line| {
+                let numbers: Vec<u64> = line.split_whitespace().map(|number| number.parse().unwrap()).collect();
+                [numbers[0], numbers[1], numbers[2]]
+            })
+            .collect())
+        .collect();
+    (seeds, maps)
+}
+
+// Map a number with the line of the map whose source range contains it, or to itself if there is none
+fn map(number: u64, map: &[[u64; 3]]) -> u64 {
+    map.iter()
+        .find(|&&[_, source, length]| source <= number && number < source + length)
+        .map_or(number, |&[destination, source, _]| destination + number - source)
+}
+
+/// Find the lowest location of the seeds by mapping every seed through every map
+pub fn part1(input: &str) -> u64 {
+    let (seeds, maps) = almanac(input);
+    seeds.into_iter()
+        .map(|seed| maps.iter().fold(seed, |number, lines| map(number, lines)))
+        .min()
+        .unwrap()
+}
+
+/// Find the lowest location of the seed ranges by mapping every range forwards through every map
+///
+/// Every range is cut at the boundaries of all source ranges, after which every piece maps as a whole like its first number.
+pub fn part2(input: &str) -> u64 {
+    let (seeds, maps) = almanac(input);
+    let mut ranges: Vec<(u64, u64)> = seeds.chunks(2).map(|pair| (pair[0], pair[0] + pair[1])).collect();
+    for lines in &maps {
+        ranges = ranges.into_iter()
+            .flat_map(|(start, end)| {
+                let mut cuts: Vec<u64> = lines.iter()
+                    .flat_map(|&[_, source, length]| [source, source + length])
+                    .filter(|&cut| start < cut && cut < end)
+                    .chain([start, end])
+                    .collect();
+                cuts.sort_unstable();
+                cuts.dedup();
+                cuts.windows(2).map(|piece| (map(piece[0], lines), map(piece[0], lines) + piece[1] - piece[0])).collect::<Vec<_>>()
+            })
+            .collect();
+    }
+    ranges.into_iter().map(|(start, _)| start).min().unwrap()
+}
@@ -0,0 +1,37 @@
+// The numbers after the label of a line
+fn numbers(line: &str) -> Vec<u64> {
+    line.split_whitespace().skip(1).map(|number| number.parse().unwrap()).collect()
+}
+
+/// Multiply the number of ways to beat the record of every race, trying every time to hold the button
+pub fn part1(input: &str) -> u64 {
+    let lines: Vec<&str> = input.lines().collect();
+    numbers(lines[0]).into_iter()
+        .zip(numbers(lines[1]))
+        .map(|(time, record)| (0..=time).filter(|hold| hold * (time - hold) > record).count() as u64)
+        .product()
+}
+
+/// Count the ways to beat the record of the race with the digits of all races concatenated
+///
+/// The distance only grows while holding the button for up to half the time, so the shortest time to hold the button
+/// that beats the record is found by a binary search, and the longest time is as far from the end of the race.
+pub fn part2(input: &str) -> u64 {
+    let lines: Vec<&str> = input.lines().collect();
+    let concatenate = |line: &str| line.split_whitespace().skip(1).collect::<String>().parse::<u128>().unwrap();
+    let (time, record) = (concatenate(lines[0]), concatenate(lines[1]));
+
+    let (mut low, mut high) = (0, time / 2 + 1);
+    while low < high {
+        let hold = (low + high) / 2;
+        if hold * (time - hold) > record {
+            high = hold;
+        } else {
+            low = hold + 1;
+        }
+    }
+    if low > time / 2 {
+        return 0;
+    }
+    (time - 2 * low + 1) as u64
+}
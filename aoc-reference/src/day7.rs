@@ -0,0 +1,56 @@
+// Type of a hand, higher is stronger, from how many cards share the label of every card, ordered from most to least
+fn strength(hand: &[u8]) -> u8 {
+    let mut counts: Vec<usize> = hand.iter().map(|card| hand.iter().filter(|&other| other == card).count()).collect();
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+    match counts[..] {
+        [5, ..] => 6,
+        [4, ..] => 5,
+        [3, 3, 3, 2, 2] => 4,
+        [3, ..] => 3,
+        [2, 2, 2, 2, 1] => 2,
+        [2, ..] => 1,
+        _ => 0,
+    }
+}
+
+// Strongest type of a hand when every joker is replaced by any other card
+fn strength_with_jokers(hand: &mut [u8]) -> u8 {
+    match hand.iter().position(|&card| card == b'J') {
+        None => strength(hand),
+        Some(joker) => {
+            let strongest = b"23456789TQKA".iter()
+                .map(|&card| {
+                    hand[joker] = card;
+                    strength_with_jokers(hand)
+                })
+                .max()
+                .unwrap();
+            hand[joker] = b'J';
+            strongest
+        }
+    }
+}
+
+// Sum the bids multiplied by the rank of the hands, ordered by their type and then by their cards
+fn winnings(input: &str, order: &[u8], strength: fn(&mut [u8]) -> u8) -> u64 {
+    let mut hands: Vec<(u8, Vec<usize>, u64)> = input.lines()
+        .map(|line| {
+            let (hand, bid) = line.split_once(' ').unwrap();
+            let mut hand = hand.as_bytes().to_vec();
+            let cards = hand.iter().map(|card| order.iter().position(|other| other == card).unwrap()).collect();
+            (strength(&mut hand), cards, bid.parse().unwrap())
+        })
+        .collect();
+    hands.sort();
+    (1..).zip(hands).map(|(rank, (_, _, bid))| rank * bid).sum()
+}
+
+/// Sum the winnings of all hands
+pub fn part1(input: &str) -> u64 {
+    winnings(input, b"23456789TJQKA", |hand| strength(hand))
+}
+
+/// Sum the winnings of all hands, where jokers act like whatever card makes the hand strongest
+pub fn part2(input: &str) -> u64 {
+    winnings(input, b"J23456789TQKA", strength_with_jokers)
+}
@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+// The steps and the left and right node of every node
+fn network(input: &str) -> (&str, HashMap<&str, [&str; 2]>) {
+    let (steps, nodes) = input.split_once("\n\n").unwrap();
+    let nodes = nodes.lines()
+        .map(|line| (&line[0..3], [&line[7..10], &line[12..15]]))
+        .collect();
+    (steps.trim(), nodes)
+}
+
+// Take the step to the left or right node
+fn next<'a>(nodes: &HashMap<&str, [&'a str; 2]>, node: &str, step: u8) -> &'a str {
+    nodes[node][usize::from(step == b'R')]
+}
+
+/// Follow the steps from AAA until reaching ZZZ
+pub fn part1(input: &str) -> u64 {
+    let (steps, nodes) = network(input);
+    let mut node = "AAA";
+    let mut count = 0;
+    for &step in steps.as_bytes().iter().cycle() {
+        if node == "ZZZ" {
+            break;
+        }
+        node = next(&nodes, node, step);
+        count += 1;
+    }
+    count
+}
+
+/// Follow the steps from every node ending with A at the same time until all of them are at a node ending with Z
+pub fn part2(input: &str) -> u64 {
+    let (steps, nodes) = network(input);
+    let mut ghosts: Vec<&str> = nodes.keys().copied().filter(|node| node.ends_with('A')).collect();
+    let mut count = 0;
+    for &step in steps.as_bytes().iter().cycle() {
+        if ghosts.iter().all(|node| node.ends_with('Z')) {
+            break;
+        }
+        for ghost in &mut ghosts {
+            *ghost = next(&nodes, ghost, step);
+        }
+        count += 1;
+    }
+    count
+}
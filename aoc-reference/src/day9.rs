@@ -0,0 +1,23 @@
+// The rows of differences of a history, down to the row of all zeroes
+fn differences(line: &str) -> Vec<Vec<i64>> {
+    let mut rows = vec![line.split_whitespace().map(|number| number.parse().unwrap()).collect::<Vec<i64>>()];
+    while rows.last().unwrap().iter().any(|&value| value != 0) {
+        let row = rows.last().unwrap().windows(2).map(|pair| pair[1] - pair[0]).collect();
+        rows.push(row);
+    }
+    rows
+}
+
+/// Sum the next value of every history, extending every row of differences from the bottom up
+pub fn part1(input: &str) -> i64 {
+    input.lines()
+        .map(|line| differences(line).iter().rev().fold(0, |below, row| row.last().unwrap_or(&0) + below))
+        .sum()
+}
+
+/// Sum the previous value of every history, extending every row of differences backwards from the bottom up
+pub fn part2(input: &str) -> i64 {
+    input.lines()
+        .map(|line| differences(line).iter().rev().fold(0, |below, row| row.first().unwrap_or(&0) - below))
+        .sum()
+}
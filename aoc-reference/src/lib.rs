@@ -0,0 +1,57 @@
+//! Slow but obviously correct reference solutions of every day, to test the fast solutions against on generated inputs.
+//!
+//! The reference solutions follow the puzzle description as literally as possible and avoid the shortcuts of the fast
+//! solutions, trading speed for simplicity. They assume well-formed input and panic on anything else.
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// A reference solution of a part of a day
+pub struct Reference {
+    pub day: u32,
+    pub part: u32,
+    /// Solve the input, formatting the answer like the fast solution
+    pub solve: fn(&str) -> String,
+    /// Largest size of generated inputs the reference solves quickly and whose answer fits the fast solution
+    pub max_size: usize,
+}
+
+/// Reference solutions of all days and parts ordered by day and part
+pub static REFERENCES: &[Reference] = &[
+    Reference { day: 1, part: 1, solve: |input| day1::part1(input).to_string(), max_size: 100 },
+    Reference { day: 1, part: 2, solve: |input| day1::part2(input).to_string(), max_size: 100 },
+    Reference { day: 2, part: 1, solve: |input| day2::part1(input).to_string(), max_size: 100 },
+    Reference { day: 2, part: 2, solve: |input| day2::part2(input).to_string(), max_size: 100 },
+    Reference { day: 3, part: 1, solve: |input| day3::part1(input).to_string(), max_size: 60 },
+    Reference { day: 3, part: 2, solve: |input| day3::part2(input).to_string(), max_size: 60 },
+    Reference { day: 4, part: 1, solve: |input| day4::part1(input).to_string(), max_size: 100 },
+    Reference { day: 4, part: 2, solve: |input| day4::part2(input).to_string(), max_size: 100 },
+    Reference { day: 5, part: 1, solve: |input| day5::part1(input).to_string(), max_size: 30 },
+    Reference { day: 5, part: 2, solve: |input| day5::part2(input).to_string(), max_size: 30 },
+    Reference { day: 6, part: 1, solve: |input| day6::part1(input).to_string(), max_size: 9 },
+    Reference { day: 6, part: 2, solve: |input| day6::part2(input).to_string(), max_size: 5 },
+    Reference { day: 7, part: 1, solve: |input| day7::part1(input).to_string(), max_size: 100 },
+    Reference { day: 7, part: 2, solve: |input| day7::part2(input).to_string(), max_size: 50 },
+    Reference { day: 8, part: 1, solve: |input| day8::part1(input).to_string(), max_size: 400 },
+    Reference { day: 8, part: 2, solve: |input| day8::part2(input).to_string(), max_size: 400 },
+    Reference { day: 9, part: 1, solve: |input| day9::part1(input).to_string(), max_size: 100 },
+    Reference { day: 9, part: 2, solve: |input| day9::part2(input).to_string(), max_size: 100 },
+    Reference { day: 10, part: 1, solve: |input| day10::part1(input).to_string(), max_size: 40 },
+    Reference { day: 10, part: 2, solve: |input| day10::part2(input).to_string(), max_size: 40 },
+    Reference { day: 11, part: 1, solve: |input| day11::part1(input).to_string(), max_size: 60 },
+    Reference { day: 11, part: 2, solve: |input| day11::part2(input).to_string(), max_size: 60 },
+];
+
+/// Find the reference solution of a part of a day
+pub fn find(day: u32, part: u32) -> Option<&'static Reference> {
+    REFERENCES.iter().find(|reference| reference.day == day && reference.part == part)
+}
@@ -0,0 +1,99 @@
+//! Compare the ways of getting the input lines to the solvers.
+//!
+//! Reading every line into its own `String` through `BufReader::lines` is compared to reading the whole file at once
+//! and to memory-mapping it, where the lines are slices of the input.
+//!
+//! The day 1 part 2 group compares ways of finding the last digit of a line: reversing every line into a `String`,
+//! taking the last overlapping match, and the anchored search from the end of the line in day 1 part 2 itself,
+//! which also finds the first digit.
+
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader};
+use std::sync::LazyLock;
+
+use aho_corasick::AhoCorasick;
+use aoc::registry::SOLVERS;
+use aoc_core::input::Source;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+/// Benchmark reading the personal input of a day and summing a function over its lines sequentially
+fn bench_input(c: &mut Criterion, day: u32, name: &str, process_line: fn(&str) -> u64) {
+    let source = SOLVERS.iter()
+        .find(|solver| solver.day == day)
+        .map(|solver| solver.default_source())
+        .unwrap();
+    let Source::File(path) = &source else { unreachable!() };
+
+    let mut group = c.benchmark_group(format!("input-{}", name));
+    group.throughput(Throughput::Bytes(fs::metadata(path).unwrap().len()));
+
+    group.bench_function("bufreader-lines", |b| b.iter(|| {
+        BufReader::new(File::open(path).unwrap())
+            .lines()
+            .map(|line| process_line(&line.unwrap()))
+            .sum::<u64>()
+    }));
+    group.bench_function("read-to-string", |b| b.iter(|| {
+        fs::read_to_string(path).unwrap()
+            .lines()
+            .map(process_line)
+            .sum::<u64>()
+    }));
+    group.bench_function("mmap", |b| b.iter(|| {
+        source.map().unwrap()
+            .lines()
+            .map(process_line)
+            .sum::<u64>()
+    }));
+
+    group.finish();
+}
+
+fn input(c: &mut Criterion) {
+    bench_input(c, 1, "day1-part1", |line| day1_part1::process_line(line).unwrap().into());
+    bench_input(c, 1, "day1-part2", |line| day1_part2::process_line(line).unwrap().into());
+    bench_input(c, 9, "day9-part1", |line| u64::try_from(day9_part1::process_line(line).unwrap()).unwrap_or_default());
+}
+
+// Words of the digits and the digits themselves
+const WORDS: [&str; 18] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+static FORWARD: LazyLock<AhoCorasick> = LazyLock::new(|| AhoCorasick::new(WORDS).unwrap());
+
+// Reversed words for matching from the end of a reversed line
+static REVERSED: LazyLock<AhoCorasick> = LazyLock::new(|| {
+    AhoCorasick::new(WORDS.map(|word| word.chars().rev().collect::<String>())).unwrap()
+});
+
+/// Find the last digit of a line by reversing it, as day 1 part 2 used to do
+fn last_digit_reversed(line: &str) -> u32 {
+    let reversed_line: String = line.chars().rev().collect();
+    REVERSED.find(&reversed_line).map_or(0, |mat| mat.pattern().as_u32() % 9 + 1)
+}
+
+/// Find the last digit of a line by taking the overlapping match that starts last
+fn last_digit_overlapping(line: &str) -> u32 {
+    FORWARD.find_overlapping_iter(line)
+        .max_by_key(|mat| mat.start())
+        .map_or(0, |mat| mat.pattern().as_u32() % 9 + 1)
+}
+
+fn last_match(c: &mut Criterion) {
+    let input = SOLVERS[0].default_source().read().unwrap();
+
+    let mut group = c.benchmark_group("day1-part2-last-digit");
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_with_input(BenchmarkId::from_parameter("reversed-string"), input.as_str(), |b, input| {
+        b.iter(|| input.lines().map(last_digit_reversed).sum::<u32>())
+    });
+    group.bench_with_input(BenchmarkId::from_parameter("overlapping"), input.as_str(), |b, input| {
+        b.iter(|| input.lines().map(last_digit_overlapping).sum::<u32>())
+    });
+    group.bench_with_input(BenchmarkId::from_parameter("anchored-process-line"), input.as_str(), |b, input| {
+        b.iter(|| input.lines().map(|line| day1_part2::process_line(line).unwrap() % 10).sum::<u32>())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, input, last_match);
+criterion_main!(benches);
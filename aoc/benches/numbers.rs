@@ -0,0 +1,74 @@
+//! Compare the ways of reading the numbers of the inputs.
+//!
+//! Splitting lines on whitespace and calling `str::parse` and folding the digit characters of every number,
+//! as the days used to do, are compared to the cursor the days read their lines with and to reading all numbers
+//! of a line directly from its bytes, which converts 8 digits at once.
+//!
+//! The concatenated digits group compares folding all digit characters of a line, as day 6 part 2 used to do,
+//! to reading the digits from the bytes. All inputs are generated, so every group reads a few megabytes.
+
+use aoc_core::int;
+use aoc_core::parse::{Cursor, RADIX};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+/// Sum all numbers of a line by folding the digit characters of every number, which only reads unsigned numbers
+fn sum_folded(line: &str) -> u64 {
+    line.split(|c: char| !c.is_ascii_digit())
+        .filter(|number| !number.is_empty())
+        .map(|number| number.chars().fold(0, |number, c| number * u64::from(RADIX) + u64::from(c.to_digit(RADIX).unwrap())))
+        .sum()
+}
+
+/// Benchmark summing the numbers of every line of a generated input in every way that can read them
+fn bench_numbers(c: &mut Criterion, day: u32, size: usize, signed: bool) {
+    let input = aoc_gen::generate(day, size, 0).unwrap();
+
+    let mut group = c.benchmark_group(format!("numbers-day{}", day));
+    group.throughput(Throughput::Bytes(input.len() as u64));
+
+    group.bench_function("str-parse", |b| b.iter(|| input.lines()
+        .flat_map(|line| line.split(|c: char| !c.is_ascii_digit() && c != '-'))
+        .filter_map(|number| number.parse::<i64>().ok())
+        .fold(0_i64, i64::wrapping_add)));
+    if !signed {
+        group.bench_function("fold-digits", |b| b.iter(|| input.lines()
+            .map(sum_folded)
+            .fold(0_u64, u64::wrapping_add)));
+    }
+    group.bench_function("cursor", |b| b.iter(|| input.lines()
+        .filter(|line| line.starts_with(|c: char| c == '-' || c.is_ascii_digit()))
+        .flat_map(|line| Cursor::new(line).numbers::<i64>().unwrap())
+        .fold(0_i64, i64::wrapping_add)));
+    group.bench_function("int-numbers", |b| b.iter(|| input.lines()
+        .flat_map(|line| int::numbers::<i64>(line.as_bytes()))
+        .map(Result::unwrap)
+        .fold(0_i64, i64::wrapping_add)));
+
+    group.finish();
+}
+
+fn numbers(c: &mut Criterion) {
+    // Day 5 has large unsigned numbers, day 9 small signed ones
+    bench_numbers(c, 5, 20_000, false);
+    bench_numbers(c, 9, 20_000, true);
+}
+
+fn concat_digits(c: &mut Criterion) {
+    // Lines of digits separated by spaces like the lines of day 6, where the digits of every line fit in 64 bits
+    let input: String = (0..100_000_u64).map(|i| format!("Time: {:>6} {:>6} {:>5}\n", i, i * 7 % 100_000, i % 1000)).collect();
+
+    let mut group = c.benchmark_group("concat-digits");
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("fold-digits", |b| b.iter(|| input.lines()
+        .map(|line| line.chars()
+            .filter_map(|c| c.to_digit(RADIX))
+            .fold(0_u64, |number, digit| number * u64::from(RADIX) + u64::from(digit)))
+        .fold(0_u64, u64::wrapping_add)));
+    group.bench_function("int-concat-digits", |b| b.iter(|| input.lines()
+        .map(|line| int::concat_digits::<u64>(line.as_bytes()).unwrap())
+        .fold(0_u64, u64::wrapping_add)));
+    group.finish();
+}
+
+criterion_group!(benches, numbers, concat_digits);
+criterion_main!(benches);
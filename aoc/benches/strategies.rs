@@ -0,0 +1,40 @@
+//! Compare the parallel strategies used throughout the days on the same inputs.
+//!
+//! Every day is run through its own `solve`, which uses the strategy the day was written with,
+//! and every line-oriented day is also run under each executor strategy. Throughput is reported in input bytes per second.
+
+use aoc::registry::SOLVERS;
+use aoc_core::executor::{Executor, Strategy};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+/// Benchmark every day through its own solve function and under every strategy it supports
+fn strategies(c: &mut Criterion) {
+    let executors = Strategy::ALL.map(|strategy| Executor::new(strategy, None));
+
+    for solver in SOLVERS {
+        let input = solver.default_source().read().unwrap();
+        // A new day solves an empty input until its input is pasted in, which has no throughput to report
+        if input.is_empty() {
+            continue;
+        }
+
+        let mut group = c.benchmark_group(format!("day{}-part{}", solver.day, solver.part));
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter("solve"), input.as_str(), |b, input| {
+            b.iter(|| (solver.solve)(input))
+        });
+
+        if let Some(solve_with) = solver.solve_with {
+            for executor in &executors {
+                group.bench_with_input(BenchmarkId::from_parameter(executor.strategy()), input.as_str(), |b, input| {
+                    b.iter(|| solve_with(input, executor))
+                });
+            }
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, strategies);
+criterion_main!(benches);
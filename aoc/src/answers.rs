@@ -0,0 +1,107 @@
+use std::fs;
+use std::path::Path;
+
+use aoc_core::report;
+use serde::{Deserialize, Serialize};
+
+/// Default location of the answers manifest, at the root of the workspace
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+/// Hash an input to the key under which its answers are recorded
+pub fn input_hash(input: &str) -> String {
+    report::sha256(input)
+}
+
+/// An accepted answer for a part of a day on a specific input
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Answer {
+    pub day: u32,
+    pub part: u32,
+    /// SHA-256 of the input the answer belongs to
+    pub input: String,
+    pub answer: String,
+}
+
+/// Manifest of all accepted answers
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    answers: Vec<Answer>,
+}
+
+impl Answers {
+    /// Load the manifest at the given path, a missing manifest has no answers
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+
+        let manifest = fs::read_to_string(path)
+            .map_err(|err| format!("Could not read answers from {}: {}", path.display(), err))?;
+        toml::from_str(&manifest)
+            .map_err(|err| format!("Could not parse answers from {}: {}", path.display(), err))
+    }
+
+    /// Save the manifest to the given path
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let manifest = toml::to_string(self)
+            .map_err(|err| format!("Could not serialize answers: {}", err))?;
+        fs::write(path, manifest)
+            .map_err(|err| format!("Could not write answers to {}: {}", path.display(), err))
+    }
+
+    /// Get the accepted answer for a part of a day on the input with the given hash
+    pub fn get(&self, day: u32, part: u32, input: &str) -> Option<&str> {
+        self.answers.iter()
+            .find(|answer| answer.day == day && answer.part == part && answer.input == input)
+            .map(|answer| answer.answer.as_str())
+    }
+
+    /// Record an accepted answer, keeping the manifest ordered by day, part and input
+    pub fn record(&mut self, answer: Answer) {
+        self.answers.retain(|other| (other.day, other.part, &other.input) != (answer.day, answer.part, &answer.input));
+        self.answers.push(answer);
+        self.answers.sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(day: u32, part: u32, input: &str, answer: &str) -> Answer {
+        Answer { day, part, input: input.to_string(), answer: answer.to_string() }
+    }
+
+    #[test]
+    fn record_replaces_and_orders_answers() {
+        let mut answers = Answers::default();
+        answers.record(answer(10, 1, "b", "3"));
+        answers.record(answer(2, 2, "a", "2"));
+        answers.record(answer(2, 1, "a", "0"));
+        answers.record(answer(2, 1, "a", "1"));
+
+        let manifest = toml::to_string(&answers).unwrap();
+        let answers: Answers = toml::from_str(&manifest).unwrap();
+
+        assert_eq!(answers.get(2, 1, "a"), Some("1"));
+        assert_eq!(answers.get(2, 2, "a"), Some("2"));
+        assert_eq!(answers.get(10, 1, "b"), Some("3"));
+        assert_eq!(answers.get(10, 1, "a"), None);
+        assert_eq!(answers.answers.iter().map(|answer| answer.day).collect::<Vec<_>>(), [2, 2, 10]);
+    }
+
+    #[test]
+    fn personal_inputs_match_recorded_answers() {
+        let answers = Answers::load(Path::new(DEFAULT_PATH)).unwrap();
+        for solver in crate::registry::SOLVERS {
+            let input = solver.default_source().read().unwrap();
+            // A new day has an empty input until the personal input is pasted in
+            if input.is_empty() {
+                continue;
+            }
+            let expected = answers.get(solver.day, solver.part, &input_hash(&input));
+            assert_eq!(expected, Some((solver.solve)(&input).unwrap().as_str()), "day {} part {}", solver.day, solver.part);
+        }
+    }
+}
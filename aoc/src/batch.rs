@@ -0,0 +1,80 @@
+use std::fs;
+use std::path::PathBuf;
+
+use clap::Args;
+
+use aoc::answers::{self, Answers};
+use aoc::crosscheck::{self, Format, Status};
+use aoc::registry;
+use crate::run::ExecutorArgs;
+
+/// Run a day on every input in a directory and report the answers, flagging those that differ from the recorded answers
+#[derive(Args)]
+pub struct BatchArgs {
+    /// Day to run
+    #[arg(short, long)]
+    day: u32,
+
+    /// Part to run, runs both parts if omitted
+    #[arg(short, long)]
+    part: Option<u32>,
+
+    /// Directory of inputs, every file in it that is not hidden is an input
+    dir: PathBuf,
+
+    /// Report format: csv, or json with an array holding an object per input and part
+    #[arg(short, long, default_value_t = Format::Csv)]
+    format: Format,
+
+    /// File to write the report to instead of stdout
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Manifest containing the recorded answers
+    #[arg(long, default_value = answers::DEFAULT_PATH)]
+    answers: PathBuf,
+
+    /// Distributes the inputs over threads, every input is solved with the strategy of the day
+    #[command(flatten)]
+    executor: ExecutorArgs,
+}
+
+/// Run the selected solvers on every input in the directory and write the report
+pub fn batch(args: BatchArgs) -> Result<(), String> {
+    let solvers = registry::select(Some(args.day), args.part)?;
+    let executor = args.executor.executor().unwrap_or_default();
+    let answers = Answers::load(&args.answers)?;
+    let inputs = crosscheck::inputs(&args.dir)?;
+    if inputs.is_empty() {
+        return Err(format!("There are no inputs in {}", args.dir.display()));
+    }
+
+    // Panicking solvers are reported as failures with the message of their panic
+    let entries = crosscheck::run(&solvers, &inputs, &answers, &executor);
+
+    let report = match args.format {
+        Format::Csv => crosscheck::to_csv(&entries),
+        Format::Json => crosscheck::to_json(&entries) + "\n",
+    };
+    match &args.output {
+        Some(path) => fs::write(path, report).map_err(|err| format!("Could not write the report to {}: {}", path.display(), err))?,
+        None => print!("{}", report),
+    }
+
+    // Summarize on stderr, so the report on stdout stays machine readable
+    let count = |status| entries.iter().filter(|entry| entry.status == status).count();
+    eprintln!(
+        "{} answers on {} inputs: {} pass, {} mismatch, {} missing, {} fail",
+        entries.len(), inputs.len(), count(Status::Pass), count(Status::Mismatch), count(Status::Missing), count(Status::Fail),
+    );
+    for entry in entries.iter().filter(|entry| entry.status == Status::Mismatch) {
+        eprintln!("{} day {} part {}: expected {}, got {}", entry.file, entry.day, entry.part, entry.expected.as_deref().unwrap_or_default(), entry.answer);
+    }
+
+    let failures = count(Status::Mismatch) + count(Status::Fail);
+    if failures > 0 {
+        return Err(format!("{} of {} answers differ from the recorded answers or could not be computed", failures, entries.len()));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+
+use clap::Args;
+
+use aoc::differential;
+use aoc::registry;
+use crate::run::ExecutorArgs;
+
+/// Compare the solvers with their slow reference solutions on generated inputs
+#[derive(Args)]
+pub struct CompareArgs {
+    /// Day to compare, compares all days if omitted
+    #[arg(short, long)]
+    day: Option<u32>,
+
+    /// Part to compare, compares both parts if omitted
+    #[arg(short, long)]
+    part: Option<u32>,
+
+    /// Number of seeds to generate an input from for every size
+    #[arg(long, default_value_t = 100)]
+    seeds: u64,
+
+    /// Largest size of the generated inputs, defaults to the largest size every reference solution supports
+    #[arg(short = 'n', long)]
+    size: Option<usize>,
+
+    #[command(flatten)]
+    executor: ExecutorArgs,
+}
+
+/// Print every generated input on which a solver disagrees with its reference solution
+pub fn compare(args: CompareArgs) -> Result<(), String> {
+    let solvers = registry::select(args.day, args.part)?;
+    let executor = args.executor.executor().unwrap_or_default();
+
+    // Panicking solvers are reported as mismatches with the message of their panic
+    let mismatches = differential::run(&solvers, args.seeds, args.size, &executor);
+
+    for mismatch in &mismatches {
+        println!("{}", mismatch);
+    }
+
+    if !mismatches.is_empty() {
+        return Err(format!("{} generated inputs gave another answer than the reference solution", mismatches.len()));
+    }
+
+    println!("All solvers agree with their reference solutions");
+    Ok(())
+}
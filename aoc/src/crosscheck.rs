@@ -0,0 +1,230 @@
+use std::fmt;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use aoc_core::executor::Executor;
+use aoc_core::input::Source;
+use aoc_core::report;
+use serde::Serialize;
+
+use crate::answers::{self, Answers};
+use crate::panic_message;
+use crate::registry::Solver;
+
+/// How a cross-check report is written
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// A header and a row of comma separated values per input, meant for spreadsheets
+    #[default]
+    Csv,
+    /// An array with an object per input, meant to be read by scripts
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Format, String> {
+        match name {
+            "csv" => Ok(Format::Csv),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Unknown format {}, expected csv or json", name)),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Format::Csv => "csv",
+            Format::Json => "json",
+        })
+    }
+}
+
+/// How the answer for an input compares with the recorded answer
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    /// The answer matches the recorded answer
+    Pass,
+    /// The answer differs from the recorded answer
+    Mismatch,
+    /// There is no recorded answer for the input
+    Missing,
+    /// The input could not be read or the solver did not produce an answer
+    Fail,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Status::Pass => "pass",
+            Status::Mismatch => "mismatch",
+            Status::Missing => "missing",
+            Status::Fail => "fail",
+        })
+    }
+}
+
+/// The answer of a part of a day on one of the inputs, compared with the recorded answer
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Entry {
+    /// Name of the input file
+    pub file: String,
+    pub day: u32,
+    pub part: u32,
+    pub status: Status,
+    /// Answer of the solver, or why it did not produce one
+    pub answer: String,
+    /// Recorded answer for the input, if any
+    pub expected: Option<String>,
+    /// Nanoseconds spent solving the puzzle
+    pub solve_ns: u64,
+    /// SHA-256 of the input as lowercase hex, empty if it could not be read
+    pub input_sha256: String,
+}
+
+/// Every file in a directory ordered by name, leaving out hidden files
+pub fn inputs(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries = fs::read_dir(dir).map_err(|err| format!("Could not read inputs from {}: {}", dir.display(), err))?;
+    let mut inputs = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|err| format!("Could not read inputs from {}: {}", dir.display(), err))?;
+        if entry.path().is_file() && !entry.file_name().to_string_lossy().starts_with('.') {
+            inputs.push(entry.path());
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// Solve an input with a solver and compare the answer with the recorded answer
+pub fn check(solver: &Solver, path: &Path, answers: &Answers) -> Entry {
+    let file = path.file_name().map_or_else(|| path.display().to_string(), |name| name.to_string_lossy().into_owned());
+    let entry = |status, answer, expected, solve_ns, input_sha256| Entry { file: file.clone(), day: solver.day, part: solver.part, status, answer, expected, solve_ns, input_sha256 };
+
+    let input = match Source::File(path.into()).map() {
+        Ok(input) => input,
+        Err(err) => return entry(Status::Fail, err.to_string(), None, 0, String::new()),
+    };
+    let input_hash = answers::input_hash(&input);
+    let expected = answers.get(solver.day, solver.part, &input_hash).map(str::to_string);
+
+    // Every input is solved with the strategy of the day, the inputs themselves are already distributed over threads
+    let (answer, solve_time) = report::time(|| panic::catch_unwind(AssertUnwindSafe(|| solver.run(&input, None))));
+    let (status, answer) = match answer {
+        Ok(Ok(answer)) if expected.as_ref().is_some_and(|expected| *expected == answer) => (Status::Pass, answer),
+        Ok(Ok(answer)) if expected.is_some() => (Status::Mismatch, answer),
+        Ok(Ok(answer)) => (Status::Missing, answer),
+        Ok(Err(err)) => (Status::Fail, err.to_string()),
+        Err(payload) => (Status::Fail, panic_message(payload)),
+    };
+
+    entry(status, answer, expected, report::nanos(solve_time), input_hash)
+}
+
+/// Run every solver on every input, distributing the inputs over the executor, ordered by input and then by solver
+pub fn run(solvers: &[&Solver], inputs: &[PathBuf], answers: &Answers, executor: &Executor) -> Vec<Entry> {
+    let cases: Vec<(&Solver, &Path)> = inputs.iter()
+        .flat_map(|path| solvers.iter().map(move |&solver| (solver, path.as_path())))
+        .collect();
+
+    executor.map_collect(&cases, |_, &(solver, path)| check(solver, path, answers))
+}
+
+/// The entries as csv with a header, quoting the fields that need it
+pub fn to_csv(entries: &[Entry]) -> String {
+    let mut csv = String::from("file,day,part,status,answer,expected,solve_ns,input_sha256\n");
+    for entry in entries {
+        let fields = [
+            entry.file.clone(),
+            entry.day.to_string(),
+            entry.part.to_string(),
+            entry.status.to_string(),
+            entry.answer.clone(),
+            entry.expected.clone().unwrap_or_default(),
+            entry.solve_ns.to_string(),
+            entry.input_sha256.clone(),
+        ];
+        let fields: Vec<String> = fields.iter()
+            .map(|field| csv_field(field))
+            .collect();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+// Quote a field that contains a separator, a quote or a line break, doubling its quotes
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// The entries as a json array with an object per entry
+pub fn to_json(entries: &[Entry]) -> String {
+    serde_json::to_string_pretty(entries).expect("Serializing a report cannot fail")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use crate::answers::Answer;
+    use crate::registry;
+
+    use super::*;
+
+    #[test]
+    fn flags_mismatches() {
+        let dir = env::temp_dir().join(format!("aoc-crosscheck-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("alice"), "1abc2\npqr3stu8vwx\n").unwrap();
+        fs::write(dir.join("bob"), "1abc2\n").unwrap();
+        fs::write(dir.join("carol"), "abc\n").unwrap();
+        fs::write(dir.join(".hidden"), "").unwrap();
+
+        let mut answers = Answers::default();
+        answers.record(Answer { day: 1, part: 1, input: answers::input_hash("1abc2\npqr3stu8vwx\n"), answer: "50".to_string() });
+        answers.record(Answer { day: 1, part: 1, input: answers::input_hash("1abc2\n"), answer: "13".to_string() });
+
+        let inputs = inputs(&dir).unwrap();
+        let solvers = registry::select(Some(1), Some(1)).unwrap();
+        let entries = run(&solvers, &inputs, &answers, &Executor::default());
+        fs::remove_dir_all(&dir).unwrap();
+
+        let outcomes: Vec<(&str, Status, &str)> = entries.iter()
+            .map(|entry| (entry.file.as_str(), entry.status, entry.answer.as_str()))
+            .collect();
+        assert_eq!(outcomes, [
+            ("alice", Status::Pass, "50"),
+            ("bob", Status::Mismatch, "12"),
+            ("carol", Status::Fail, "line 1, column 4: expected a digit, found the end of the line"),
+        ]);
+        assert_eq!(entries[1].expected.as_deref(), Some("13"));
+    }
+
+    #[test]
+    fn csv() {
+        let entry = Entry {
+            file: "a,b".to_string(),
+            day: 1,
+            part: 2,
+            status: Status::Fail,
+            answer: "expected \"x\"".to_string(),
+            expected: None,
+            solve_ns: 10,
+            input_sha256: "ab".to_string(),
+        };
+        assert_eq!(to_csv(&[entry]), concat!(
+            "file,day,part,status,answer,expected,solve_ns,input_sha256\n",
+            "\"a,b\",1,2,fail,\"expected \"\"x\"\"\",,10,ab\n",
+        ));
+    }
+}
@@ -0,0 +1,96 @@
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use aoc_core::executor::Executor;
+use aoc_reference::Reference;
+
+use crate::panic_message;
+use crate::registry::Solver;
+
+/// A generated input on which a solver disagrees with the reference solution
+#[derive(Debug)]
+pub struct Mismatch {
+    pub day: u32,
+    pub part: u32,
+    pub size: usize,
+    pub seed: u64,
+    /// Answer of the reference solution
+    pub expected: String,
+    /// Answer of the solver, or why it did not produce one
+    pub found: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "day {} part {} on size {} seed {}: expected {}, found {}", self.day, self.part, self.size, self.seed, self.expected, self.found)
+    }
+}
+
+/// Sizes from 1 doubling up to the largest size, which is always included
+pub fn sizes(max_size: usize) -> Vec<usize> {
+    let mut sizes: Vec<usize> = (0..)
+        .map(|exponent| 1 << exponent)
+        .take_while(|&size| size < max_size)
+        .collect();
+    sizes.push(max_size.max(1));
+    sizes
+}
+
+/// Solve the input generated from the size and seed with both the solver and the reference solution and compare the answers
+pub fn compare(solver: &Solver, reference: &Reference, size: usize, seed: u64) -> Result<(), Mismatch> {
+    let input = aoc_gen::generate(solver.day, size, seed).expect("every day has a generator");
+    let expected = (reference.solve)(&input);
+    let found = match panic::catch_unwind(AssertUnwindSafe(|| (solver.solve)(&input))) {
+        Ok(Ok(answer)) if answer == expected => return Ok(()),
+        Ok(Ok(answer)) => answer,
+        Ok(Err(err)) => format!("the error {}", err),
+        Err(payload) => format!("a panic: {}", panic_message(payload)),
+    };
+
+    Err(Mismatch { day: solver.day, part: solver.part, size, seed, expected, found })
+}
+
+/// Compare the solvers with their reference solutions on the inputs generated from every seed for every size up to the
+/// largest size the reference solution supports, or the given size, distributing the inputs over the executor
+pub fn run(solvers: &[&Solver], seeds: u64, max_size: Option<usize>, executor: &Executor) -> Vec<Mismatch> {
+    let cases: Vec<(&Solver, &Reference, usize, u64)> = solvers.iter()
+        // Days that were only just added do not have a reference solution yet
+        .filter_map(|&solver| aoc_reference::find(solver.day, solver.part).map(|reference| (solver, reference)))
+        .flat_map(|(solver, reference)| sizes(max_size.unwrap_or(reference.max_size)).into_iter().map(move |size| (solver, reference, size)))
+        .flat_map(|(solver, reference, size)| (0..seeds).map(move |seed| (solver, reference, size, seed)))
+        .collect();
+
+    executor.map_collect(&cases, |_, &(solver, reference, size, seed)| compare(solver, reference, size, seed).err())
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::SOLVERS;
+
+    use super::*;
+
+    #[test]
+    fn solvers_agree_with_references() {
+        let solvers: Vec<&Solver> = SOLVERS.iter().collect();
+        let mismatches = run(&solvers, 20, None, &Executor::default());
+        assert!(mismatches.is_empty(), "{} mismatches, the first being {}", mismatches.len(), mismatches[0]);
+    }
+
+    #[test]
+    fn large_numbers_agree_with_references() {
+        // Numbers far beyond the generated ones, up to the largest number that fits without the `checked` feature
+        let inputs = [
+            "seeds: 5000000000000000000 1\n\nseed-to-soil map:\n0 0 1\n",
+            "seeds: 0 2 7 3\n\nseed-to-soil map:\n9223372036854775806 0 2\n\nsoil-to-fertilizer map:\n1 9223372036854775807 1\n",
+        ];
+        for solver in SOLVERS.iter().filter(|solver| solver.day == 5) {
+            let reference = aoc_reference::find(solver.day, solver.part).unwrap();
+            for input in inputs {
+                assert_eq!((solver.solve)(input), Ok((reference.solve)(input)), "day {} part {} on {:?}", solver.day, solver.part, input);
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use clap::Args;
+
+/// Generate a synthetic input for a day
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// Day to generate an input for
+    #[arg(short, long, required_unless_present = "list")]
+    day: Option<u32>,
+
+    /// Size of the input, whose meaning depends on the day: see `aoc generate --list`
+    #[arg(short = 'n', long, required_unless_present = "list")]
+    size: Option<usize>,
+
+    /// Seed of the random number generator, the same seed always generates the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// File to write the input to instead of stdout
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// List what the size means for every day instead of generating an input
+    #[arg(short, long, exclusive = true)]
+    list: bool,
+}
+
+/// Generate the input and write it to stdout or the output file
+pub fn generate(args: GenerateArgs) -> Result<(), String> {
+    if args.list {
+        for generator in aoc_gen::GENERATORS {
+            println!("Day {:>2}: {}", generator.day, generator.size);
+        }
+        return Ok(());
+    }
+
+    let (Some(day), Some(size)) = (args.day, args.size) else {
+        return Err("Both --day and --size are required".to_string());
+    };
+    let input = aoc_gen::generate(day, size, args.seed).ok_or_else(|| format!("There is no generator for day {}", day))?;
+
+    match &args.output {
+        Some(path) => fs::write(path, input).map_err(|err| format!("Could not write {}: {}", path.display(), err)),
+        None => io::stdout().write_all(input.as_bytes()).map_err(|err| format!("Could not write the input: {}", err)),
+    }
+}
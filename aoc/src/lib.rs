@@ -0,0 +1,16 @@
+//! Registry of all solvers and the recorded answers, shared by the `aoc` binary and the benchmarks.
+
+use std::any::Any;
+
+pub mod answers;
+pub mod crosscheck;
+pub mod differential;
+pub mod registry;
+pub mod scaffold;
+
+/// Extract the message of a panic
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload.downcast_ref::<&str>().map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Solver panicked".to_string())
+}
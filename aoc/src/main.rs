@@ -0,0 +1,52 @@
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+mod batch;
+mod compare;
+mod generate;
+mod new;
+mod run;
+mod verify;
+mod watch;
+
+/// Runner for all solutions of Advent of Code 2023
+#[derive(Parser)]
+#[command(name = "aoc", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    Batch(batch::BatchArgs),
+    Compare(compare::CompareArgs),
+    Generate(generate::GenerateArgs),
+    New(new::NewArgs),
+    Run(run::RunArgs),
+    Verify(verify::VerifyArgs),
+    Watch(watch::WatchArgs),
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Batch(args) => batch::batch(args),
+        Command::Compare(args) => compare::compare(args),
+        Command::Generate(args) => generate::generate(args),
+        Command::New(args) => new::new(args),
+        Command::Run(args) => run::run(args),
+        Command::Verify(args) => verify::verify(args),
+        Command::Watch(args) => watch::watch(args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
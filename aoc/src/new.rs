@@ -0,0 +1,26 @@
+use std::path::Path;
+
+use clap::Args;
+
+use aoc::scaffold;
+
+/// Create the crates of a new day from a template and register them with the runner
+#[derive(Args)]
+pub struct NewArgs {
+    /// Day to create
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+}
+
+/// Create the new day and print the files that were created or changed
+pub fn new(args: NewArgs) -> Result<(), String> {
+    let root = Path::new(scaffold::WORKSPACE);
+    for path in scaffold::new_day(root, args.day)? {
+        println!("{}", path.strip_prefix(root).unwrap_or(&path).display());
+    }
+
+    println!();
+    println!("Paste the input into day{}/input and fill in the examples, then record the answers with", args.day);
+    println!("cargo run --release -p aoc -- verify --day {} --record", args.day);
+    Ok(())
+}
@@ -0,0 +1,260 @@
+use std::io::{self, BufRead};
+use std::path::Path;
+
+use aoc_core::error::ParseError;
+use aoc_core::executor::Executor;
+use aoc_core::explain::Explanation;
+use aoc_core::input::Source;
+
+/// The formatted answer of a solver, or where the input could not be parsed
+pub type Solution = Result<String, ParseError>;
+
+/// What every line of an input contributes to the answer of a solver, or where the input could not be parsed
+pub type Explanations = Result<Vec<Explanation>, ParseError>;
+
+/// The formatted answer of a solver that read its input as a stream, or why the input could not be read or parsed
+pub type Streamed = io::Result<String>;
+
+/// A solver for a single part of a day
+pub struct Solver {
+    pub day: u32,
+    pub part: u32,
+    /// Path of the personal input file of the day
+    pub input: &'static str,
+    /// Solve the puzzle for the given input and format the answer
+    pub solve: fn(&str) -> Solution,
+    /// Solve the puzzle with the given executor, if the day is line-oriented
+    pub solve_with: Option<fn(&str, &Executor) -> Solution>,
+    /// The executor the day runs with by default, days that are not line-oriented run on a single thread
+    pub executor: fn() -> Executor,
+    /// Explain what every line of the input contributes to the answer, if the day supports it
+    pub explain: Option<fn(&str) -> Explanations>,
+    /// Solve the puzzle with the given executor while reading the lines from a reader, if the day supports it
+    pub stream: Option<fn(&mut (dyn BufRead + Send), &Executor) -> Streamed>,
+    /// Solve the puzzle with the given executor while reading the file in a chunk per thread, if the day supports it
+    pub read_file: Option<fn(&Path, &Executor) -> Streamed>,
+}
+
+impl Solver {
+    /// The personal input file of the day
+    pub fn default_source(&self) -> Source {
+        Source::File(self.input.into())
+    }
+
+    /// Solve the puzzle with the executor if one is given and the day supports it, or with its own strategy otherwise
+    pub fn run(&self, input: &str, executor: Option<&Executor>) -> Solution {
+        match (self.solve_with, executor) {
+            (Some(solve_with), Some(executor)) => solve_with(input, executor),
+            _ => (self.solve)(input),
+        }
+    }
+
+    /// Solve the puzzle while reading it from the reader with the executor if one is given or with its own strategy
+    /// otherwise, or `None` if the day cannot read its input as a stream
+    pub fn run_reader(&self, reader: &mut (dyn BufRead + Send), executor: Option<&Executor>) -> Option<Streamed> {
+        let stream = self.stream?;
+        Some(match executor {
+            Some(executor) => stream(reader, executor),
+            None => stream(reader, &(self.executor)()),
+        })
+    }
+
+    /// Solve the puzzle while reading the file in chunks with the executor if one is given or with its own strategy
+    /// otherwise, or `None` if the day cannot read a file in chunks
+    pub fn run_file(&self, path: &Path, executor: Option<&Executor>) -> Option<Streamed> {
+        let read_file = self.read_file?;
+        Some(match executor {
+            Some(executor) => read_file(path, executor),
+            None => read_file(path, &(self.executor)()),
+        })
+    }
+
+    /// Number of threads [Solver::run] uses with the given executor
+    pub fn threads(&self, executor: Option<&Executor>) -> usize {
+        match (self.solve_with, executor) {
+            (Some(_), Some(executor)) => executor.threads(),
+            _ => (self.executor)().threads(),
+        }
+    }
+}
+
+/// Select the solvers of a day and part, or all days or parts if they are not given
+pub fn select(day: Option<u32>, part: Option<u32>) -> Result<Vec<&'static Solver>, String> {
+    let solvers: Vec<&Solver> = SOLVERS.iter()
+        .filter(|solver| day.is_none_or(|day| solver.day == day))
+        .filter(|solver| part.is_none_or(|part| solver.part == part))
+        .collect();
+
+    if solvers.is_empty() {
+        return Err(match (day, part) {
+            (Some(day), Some(part)) => format!("There is no solver for day {} part {}", day, part),
+            (Some(day), None) => format!("There is no solver for day {}", day),
+            (None, Some(part)) => format!("There is no solver for part {}", part),
+            (None, None) => "There are no solvers registered".to_string(),
+        });
+    }
+
+    Ok(solvers)
+}
+
+// Register both parts of every day, where each part is a crate exposing a solve function
+// Parts are followed by markers in brackets for what else they expose:
+// line-oriented days are marked with `lines` and also expose a solve_with function taking an executor,
+// together with the executor they run with by default, days marked with `explain` expose an explain function
+// days marked with `stream` expose a solve_reader function taking a reader and an executor
+// and days marked with `file` expose a solve_file function taking the path of a file and an executor
+macro_rules! register {
+    (@solve_with $part:ident lines $($rest:ident)*) => {
+        Some(|input, executor| $part::solve_with(input, executor).map(|answer| answer.to_string()))
+    };
+    (@executor $part:ident lines $($rest:ident)*) => {
+        $part::executor
+    };
+    (@explain $part:ident explain $($rest:ident)*) => {
+        Some($part::explain)
+    };
+    (@stream $part:ident stream $($rest:ident)*) => {
+        Some(|reader, executor| $part::solve_reader(reader, executor).map(|answer| answer.to_string()))
+    };
+    (@read_file $part:ident file $($rest:ident)*) => {
+        Some(|path, executor| $part::solve_file(path, executor).map(|answer| answer.to_string()))
+    };
+    // Skip the markers that do not apply
+    (@$field:ident $part:ident $other:ident $($rest:ident)*) => {
+        register!(@$field $part $($rest)*)
+    };
+    (@solve_with $part:ident) => {
+        None
+    };
+    (@executor $part:ident) => {
+        Executor::sequential
+    };
+    (@explain $part:ident) => {
+        None
+    };
+    (@stream $part:ident) => {
+        None
+    };
+    (@read_file $part:ident) => {
+        None
+    };
+    ($($day:literal => $part1:ident $([$($marker1:ident)*])?, $part2:ident $([$($marker2:ident)*])?;)*) => {
+        /// All registered solvers ordered by day and part
+        pub static SOLVERS: &[Solver] = &[$(
+            Solver {
+                day: $day,
+                part: 1,
+                input: concat!(env!("CARGO_MANIFEST_DIR"), "/../day", $day, "/input"),
+                solve: |input| $part1::solve(input).map(|answer| answer.to_string()),
+                solve_with: register!(@solve_with $part1 $($($marker1)*)?),
+                executor: register!(@executor $part1 $($($marker1)*)?),
+                explain: register!(@explain $part1 $($($marker1)*)?),
+                stream: register!(@stream $part1 $($($marker1)*)?),
+                read_file: register!(@read_file $part1 $($($marker1)*)?),
+            },
+            Solver {
+                day: $day,
+                part: 2,
+                input: concat!(env!("CARGO_MANIFEST_DIR"), "/../day", $day, "/input"),
+                solve: |input| $part2::solve(input).map(|answer| answer.to_string()),
+                solve_with: register!(@solve_with $part2 $($($marker2)*)?),
+                executor: register!(@executor $part2 $($($marker2)*)?),
+                explain: register!(@explain $part2 $($($marker2)*)?),
+                stream: register!(@stream $part2 $($($marker2)*)?),
+                read_file: register!(@read_file $part2 $($($marker2)*)?),
+            },
+        )*];
+    };
+}
+
+register! {
+    1 => day1_part1 [lines explain stream], day1_part2 [lines explain stream];
+    2 => day2_part1 [lines explain stream], day2_part2 [lines explain stream];
+    3 => day3_part1 [lines file], day3_part2 [lines file];
+    4 => day4_part1 [lines explain stream], day4_part2 [lines explain stream];
+    5 => day5_part1, day5_part2;
+    6 => day6_part1, day6_part2;
+    7 => day7_part1 [lines explain stream], day7_part2 [lines explain stream];
+    8 => day8_part1 [lines], day8_part2 [lines];
+    9 => day9_part1 [lines explain stream], day9_part2 [lines explain stream];
+    10 => day10_part1 [lines], day10_part2 [lines];
+    11 => day11_part1 [lines], day11_part2 [lines];
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::executor::Strategy;
+
+    use super::*;
+
+    #[test]
+    fn strategies_agree_with_solve() {
+        for solver in SOLVERS.iter().filter(|solver| solver.solve_with.is_some()) {
+            let input = solver.default_source().read().unwrap();
+            let expected = (solver.solve)(&input);
+            for strategy in Strategy::ALL {
+                for threads in [1, 3] {
+                    let executor = Executor::new(strategy, Some(threads));
+                    assert_eq!(solver.run(&input, Some(&executor)), expected, "day {} part {} with {} on {} threads", solver.day, solver.part, strategy, threads);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn strategies_agree_on_generated_inputs() {
+        for solver in SOLVERS.iter().filter(|solver| solver.solve_with.is_some()) {
+            for size in [1, 9, 200] {
+                for seed in 0..4 {
+                    let input = aoc_gen::generate(solver.day, size, seed).unwrap();
+                    let expected = (solver.solve)(&input);
+                    assert!(expected.is_ok(), "day {} part {} size {} seed {}: {:?}", solver.day, solver.part, size, seed, expected);
+
+                    let executor = Executor::new(Strategy::Chunked, Some(3));
+                    assert_eq!(solver.run(&input, Some(&executor)), expected, "day {} part {} size {} seed {}", solver.day, solver.part, size, seed);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn streams_agree_with_solve() {
+        for solver in SOLVERS.iter().filter(|solver| solver.stream.is_some()) {
+            let input = solver.default_source().read().unwrap();
+            let expected = (solver.solve)(&input).unwrap();
+            for strategy in Strategy::ALL {
+                let executor = Executor::new(strategy, Some(3));
+                let answer = solver.run_reader(&mut input.as_bytes(), Some(&executor)).unwrap().unwrap();
+                assert_eq!(answer, expected, "day {} part {} with {}", solver.day, solver.part, strategy);
+            }
+        }
+    }
+
+    #[test]
+    fn files_agree_with_solve() {
+        for solver in SOLVERS.iter().filter(|solver| solver.read_file.is_some()) {
+            let source = solver.default_source();
+            let expected = (solver.solve)(&source.read().unwrap()).unwrap();
+            let Source::File(path) = source else {
+                unreachable!("The personal input of a day is a file");
+            };
+            for threads in [1, 3] {
+                let executor = Executor::new(Strategy::Chunked, Some(threads));
+                let answer = solver.run_file(&path, Some(&executor)).unwrap().unwrap();
+                assert_eq!(answer, expected, "day {} part {} with {} threads", solver.day, solver.part, threads);
+            }
+        }
+    }
+
+    #[test]
+    fn explanations_cover_every_line() {
+        for solver in SOLVERS {
+            let Some(explain) = solver.explain else {
+                continue;
+            };
+            let input = solver.default_source().read().unwrap();
+            let explanations = explain(&input).unwrap();
+            assert_eq!(explanations.len(), input.lines().count(), "day {} part {}", solver.day, solver.part);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use std::env;
+use std::ffi::OsString;
+use std::io::{self, BufReader};
+
+use aoc_core::error::ParseError;
+use aoc_core::executor::{Executor, Strategy};
+use aoc_core::explain;
+use aoc_core::input::{Input, Source, INPUT_VAR};
+use aoc_core::report::{self, Format, Report, Sha256Reader};
+use aoc_core::trace::{self, Mode};
+use clap::builder::RangedU64ValueParser;
+use clap::Args;
+use tracing::info_span;
+
+use aoc::registry::{self, Solver};
+
+/// Run the solvers of one or more days and print their answers
+#[derive(Args)]
+pub struct RunArgs {
+    /// Day to run
+    #[arg(short, long, required_unless_present = "all")]
+    day: Option<u32>,
+
+    /// Part to run, runs both parts if omitted
+    #[arg(short, long, requires = "day")]
+    part: Option<u32>,
+
+    /// Input file to use instead of the personal input of the day, `-` reads from stdin
+    ///
+    /// Defaults to the AOC_INPUT environment variable if it is set.
+    #[arg(short, long, requires = "day")]
+    input: Option<OsString>,
+
+    /// Run every registered day and part
+    #[arg(short, long, conflicts_with = "day")]
+    all: bool,
+
+    /// Output format: text, or json with one object per line holding the answer, timings, threads and input hash
+    #[arg(short, long, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Record the phases of the solvers: `phases` prints the time spent in every phase,
+    /// `chrome` or `chrome=path` writes a Chrome trace of every thread to trace.json or the path
+    ///
+    /// Defaults to the AOC_TRACE environment variable if it is set.
+    #[arg(long, env = trace::TRACE_VAR)]
+    trace: Option<Mode>,
+
+    /// Print what every line of the input contributes before the answer, as a table or as a json object per line
+    ///
+    /// Only the line-oriented days 1, 2, 4, 7 and 9 can explain their lines.
+    #[arg(long, requires = "day")]
+    explain: bool,
+
+    /// Read the input line by line while solving it, instead of loading the whole input first
+    ///
+    /// Only the line solvers of days 1, 2, 4, 7 and 9 can read their input as a stream,
+    /// day 3 instead reads its input file in a chunk per thread and cannot read stdin.
+    #[arg(long, conflicts_with = "explain")]
+    stream: bool,
+
+    #[command(flatten)]
+    executor: ExecutorArgs,
+}
+
+/// Select how the line-oriented days distribute their work over threads
+#[derive(Args)]
+pub struct ExecutorArgs {
+    /// Execution strategy: sequential, rayon, chunked or shared
+    ///
+    /// Defaults to the strategy each day was written with, or rayon if only --threads is given.
+    /// Days that are not line-oriented always use their own strategy.
+    #[arg(short, long)]
+    strategy: Option<Strategy>,
+
+    /// Number of threads, defaults to one per cpu
+    #[arg(short, long, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    threads: Option<usize>,
+}
+
+impl ExecutorArgs {
+    /// The executor to run the solvers with, or none to let every day use its own strategy
+    pub fn executor(&self) -> Option<Executor> {
+        if self.strategy.is_none() && self.threads.is_none() {
+            return None;
+        }
+
+        Some(Executor::new(self.strategy.unwrap_or(Strategy::Rayon), self.threads))
+    }
+}
+
+/// Run the selected solvers
+pub fn run(args: RunArgs) -> Result<(), String> {
+    // Select the solvers to run
+    let solvers = registry::select(args.day, args.part)?;
+    if args.explain {
+        if let Some(solver) = solvers.iter().find(|solver| solver.explain.is_none()) {
+            let days = supported(|solver| solver.explain.is_some());
+            return Err(format!("Day {} part {} cannot explain its lines, only days {} can", solver.day, solver.part, days));
+        }
+    }
+    if args.stream {
+        if let Some(solver) = solvers.iter().find(|solver| solver.stream.is_none() && solver.read_file.is_none()) {
+            let days = supported(|solver| solver.stream.is_some() || solver.read_file.is_some());
+            return Err(format!("Day {} part {} cannot read its input as a stream, only days {} can", solver.day, solver.part, days));
+        }
+    }
+    let executor = args.executor.executor();
+    let _trace = trace::init(args.trace.as_ref())?;
+
+    if args.stream {
+        return stream(&solvers, args.input, args.format, executor.as_ref());
+    }
+
+    // Read the input given as argument or through the environment once, since stdin can only be read once
+    let shared_input = read_shared_input(args.input)?;
+
+    for solver in solvers {
+        // Read the input of the day unless another input was given
+        let day_input;
+        let input: &str = match &shared_input {
+            Some(input) => input,
+            None => {
+                day_input = info_span!("load").in_scope(|| solver.default_source().map()).map_err(|err| err.to_string())?;
+                &day_input
+            }
+        };
+
+        // Explain the lines before solving, so the answer follows them
+        if let Some(explain) = solver.explain.filter(|_| args.explain) {
+            let explanations = explain(input)
+                .map_err(|err| format!("Could not parse the input of day {} part {}: {}", solver.day, solver.part, err.diagnostic()))?;
+            match args.format {
+                Format::Text => print!("{}", explain::table(input, &explanations)),
+                Format::Json => explanations.iter()
+                    .enumerate()
+                    .for_each(|(index, explanation)| println!("{}", explanation.to_json(solver.day, solver.part, index + 1))),
+            }
+        }
+
+        // Solve the puzzle and time the parsing and the solving apart
+        let span = info_span!("day", day = solver.day, part = solver.part);
+        let (answer, parse_time, solve_time) = report::time_phases(|| span.in_scope(|| solver.run(input, executor.as_ref())));
+        let answer = answer
+            .map_err(|err| format!("Could not parse the input of day {} part {}: {}", solver.day, solver.part, err.diagnostic()))?;
+
+        match args.format {
+            Format::Text => println!("Day {:>2} part {}: {:<20} ({:.2?})", solver.day, solver.part, answer, parse_time + solve_time),
+            Format::Json => {
+                let report = Report {
+                    day: solver.day,
+                    part: solver.part,
+                    answer,
+                    parse_ns: report::nanos(parse_time),
+                    solve_ns: report::nanos(solve_time),
+                    threads: solver.threads(executor.as_ref()),
+                    input_sha256: report::sha256(input),
+                };
+                println!("{}", report.to_json());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// List the days that support something, naming the part of a day where only one part does
+fn supported(supports: impl Fn(&Solver) -> bool) -> String {
+    let days: Vec<String> = registry::SOLVERS.chunk_by(|a, b| a.day == b.day)
+        .filter_map(|parts| match parts.iter().filter(|solver| supports(solver)).collect::<Vec<_>>()[..] {
+            [] => None,
+            [solver] if parts.len() > 1 => Some(format!("{} part {}", solver.day, solver.part)),
+            [solver, ..] => Some(solver.day.to_string()),
+        })
+        .collect();
+    days.join(", ")
+}
+
+// Solve the solvers while reading their input as a stream, which is opened again for every solver
+fn stream(solvers: &[&Solver], argument: Option<OsString>, format: Format, executor: Option<&Executor>) -> Result<(), String> {
+    let shared_source = argument.or_else(|| env::var_os(INPUT_VAR)).map(Source::from);
+    if shared_source == Some(Source::Stdin) && solvers.len() > 1 {
+        return Err("Stdin can only be read as a stream once, select a single part to stream it".to_string());
+    }
+
+    for solver in solvers {
+        let source = shared_source.clone().unwrap_or_else(|| solver.default_source());
+
+        // Reading the input is part of parsing and solving it, so there is no separate time spent loading it
+        // Days that read a file in chunks read it from several threads at once, so it is not hashed while it is read
+        let span = info_span!("day", day = solver.day, part = solver.part);
+        let (answer, parse_time, solve_time, reader) = match &source {
+            Source::File(path) if solver.read_file.is_some() => {
+                let (answer, parse_time, solve_time) = report::time_phases(|| span.in_scope(|| solver.run_file(path, executor)));
+                (answer, parse_time, solve_time, None)
+            }
+            _ => {
+                let mut reader = BufReader::new(Sha256Reader::new(source.reader().map_err(|err| err.to_string())?));
+                let (answer, parse_time, solve_time) = report::time_phases(|| span.in_scope(|| solver.run_reader(&mut reader, executor)));
+                (answer, parse_time, solve_time, Some(reader))
+            }
+        };
+        let answer = answer
+            .ok_or_else(|| format!("Day {} part {} can only read its input in chunks from a file, not from {}", solver.day, solver.part, source))?
+            .map_err(|err| match err.get_ref().and_then(|err| err.downcast_ref::<ParseError>()) {
+                Some(err) => format!("Could not parse the input of day {} part {}: {}", solver.day, solver.part, err.diagnostic()),
+                None => format!("Could not read the input of day {} part {} from {}: {}", solver.day, solver.part, source, err),
+            })?;
+
+        match format {
+            Format::Text => println!("Day {:>2} part {}: {:<20} ({:.2?})", solver.day, solver.part, answer, parse_time + solve_time),
+            Format::Json => {
+                let report = Report {
+                    day: solver.day,
+                    part: solver.part,
+                    answer,
+                    parse_ns: report::nanos(parse_time),
+                    solve_ns: report::nanos(solve_time),
+                    threads: solver.threads(executor),
+                    input_sha256: match reader {
+                        Some(reader) => reader.into_inner().finish(),
+                        None => hash(&source).map_err(|err| format!("Could not hash the input of day {} part {} from {}: {}", solver.day, solver.part, source, err))?,
+                    },
+                };
+                println!("{}", report.to_json());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Hash an input by reading it once more
+fn hash(source: &Source) -> io::Result<String> {
+    let mut reader = Sha256Reader::new(source.reader()?);
+    io::copy(&mut reader, &mut io::sink())?;
+    Ok(reader.finish())
+}
+
+/// Read the input given as argument or through the `AOC_INPUT` environment variable, if any
+pub fn read_shared_input(argument: Option<OsString>) -> Result<Option<Input>, String> {
+    argument
+        .or_else(|| env::var_os(INPUT_VAR))
+        .map(|argument| Source::from(argument).map().map_err(|err| err.to_string()))
+        .transpose()
+}
@@ -0,0 +1,249 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Root of the workspace, which holds a directory per day
+pub const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+const MANIFEST: &str = include_str!("../templates/Cargo.toml.template");
+const LIB: &str = include_str!("../templates/lib.rs.template");
+const MAIN: &str = include_str!("../templates/main.rs.template");
+
+/// Create the crates of both parts of a new day in the workspace and register them with the runner,
+/// returning the paths of the files that were created or changed
+///
+/// The day is benchmarked by the strategies benchmark as soon as it is registered and its input is pasted in.
+pub fn new_day(root: &Path, day: u32) -> Result<Vec<PathBuf>, String> {
+    let dir = root.join(format!("day{}", day));
+    if dir.exists() {
+        return Err(format!("Day {} already exists at {}", day, dir.display()));
+    }
+
+    // Prepare every change before writing anything, and undo the writes if one fails, so a day is either added
+    // completely or not at all
+    let workspace_path = root.join("Cargo.toml");
+    let runner_path = root.join("aoc/Cargo.toml");
+    let registry_path = root.join("aoc/src/registry.rs");
+    let originals = [read(&workspace_path)?, read(&runner_path)?, read(&registry_path)?];
+    let workspace = add_workspace_dependencies(&originals[0], day)?;
+    let runner = add_runner_dependencies(&originals[1], day)?;
+    let registry = register(&originals[2], day)?;
+
+    let mut files = vec![
+        (workspace_path.clone(), workspace),
+        (runner_path.clone(), runner),
+        (registry_path.clone(), registry),
+    ];
+    for part in [1, 2] {
+        let fill = |template: &str| template.replace("{{day}}", &day.to_string()).replace("{{part}}", &part.to_string());
+        let part_dir = dir.join(format!("part{}", part));
+        files.push((part_dir.join("Cargo.toml"), fill(MANIFEST)));
+        files.push((part_dir.join("src/lib.rs"), fill(LIB)));
+        files.push((part_dir.join("src/main.rs"), fill(MAIN)));
+    }
+    // The personal input is pasted in later, until then the day solves an empty input
+    files.push((dir.join("input"), String::new()));
+
+    if let Err(err) = write_all(&files) {
+        let _ = fs::remove_dir_all(&dir);
+        for (path, original) in [workspace_path, runner_path, registry_path].iter().zip(&originals) {
+            let _ = fs::write(path, original);
+        }
+        return Err(err);
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+// Write every file, creating the directories it is in
+fn write_all(files: &[(PathBuf, String)]) -> Result<(), String> {
+    for (path, contents) in files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|err| format!("Could not create {}: {}", parent.display(), err))?;
+        }
+        fs::write(path, contents).map_err(|err| format!("Could not write {}: {}", path.display(), err))?;
+    }
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("Could not read {}: {}", path.display(), err))
+}
+
+// Day of a line starting with the name of one of its crates, such as `day12-part1`
+fn crate_day(line: &str) -> Option<u32> {
+    line.strip_prefix("day")?.split_once("-part")?.0.parse().ok()
+}
+
+/// Add both parts of the day to the dependencies of the workspace manifest
+pub fn add_workspace_dependencies(manifest: &str, day: u32) -> Result<String, String> {
+    let lines = [1, 2].map(|part| format!("day{}-part{} = {{ path = \"day{}/part{}\" }}", day, part, day, part));
+    insert_ordered(manifest, day, |line| crate_day(line).filter(|_| line.contains("= { path")), &lines)
+        .ok_or_else(|| format!("Could not add day {} to the dependencies of the workspace", day))
+}
+
+/// Add both parts of the day to the dependencies of the runner manifest and to its `checked` feature
+pub fn add_runner_dependencies(manifest: &str, day: u32) -> Result<String, String> {
+    let lines = [1, 2].map(|part| format!("day{}-part{}.workspace = true", day, part));
+    let manifest = insert_ordered(manifest, day, |line| crate_day(line).filter(|_| line.ends_with(".workspace = true")), &lines)
+        .ok_or_else(|| format!("Could not add day {} to the dependencies of the runner", day))?;
+
+    // The features of the checked feature are quoted and indented, such as `    "day12-part1/checked",`
+    let lines = [1, 2].map(|part| format!("    \"day{}-part{}/checked\",", day, part));
+    let feature_day = |line: &str| line.trim_start().strip_prefix('"').filter(|_| line.ends_with("/checked\",")).and_then(crate_day);
+    insert_ordered(&manifest, day, feature_day, &lines)
+        .ok_or_else(|| format!("Could not add day {} to the checked feature of the runner", day))
+}
+
+/// Add the day to the invocation of the `register!` macro of the registry
+pub fn register(registry: &str, day: u32) -> Result<String, String> {
+    let line = format!("    {} => day{}_part1, day{}_part2;", day, day, day);
+    let entry_day = |line: &str| {
+        let (day, parts) = line.trim_start().split_once(" => ")?;
+        parts.starts_with("day").then(|| day.parse().ok()).flatten()
+    };
+    insert_ordered(registry, day, entry_day, &[line])
+        .ok_or_else(|| format!("Could not register day {} with the runner", day))
+}
+
+// Insert lines among the entries of a list ordered by day, which are the lines the function finds a day in,
+// failing if there are no entries or the day already has one
+fn insert_ordered(text: &str, day: u32, entry_day: impl Fn(&str) -> Option<u32>, new_lines: &[String]) -> Option<String> {
+    let entries: Vec<(usize, u32)> = text.lines()
+        .enumerate()
+        .filter_map(|(index, line)| entry_day(line).map(|day| (index, day)))
+        .collect();
+    if entries.iter().any(|&(_, entry)| entry == day) {
+        return None;
+    }
+
+    // Insert before the first later day, or after the last day
+    let position = match entries.iter().find(|&&(_, entry)| entry > day) {
+        Some(&(index, _)) => index,
+        None => entries.last()?.0 + 1,
+    };
+    let mut lines: Vec<&str> = text.lines().collect();
+    lines.splice(position..position, new_lines.iter().map(String::as_str));
+
+    let mut text = lines.join("\n");
+    text.push('\n');
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    const WORKSPACE_MANIFEST: &str = "\
+[workspace.dependencies]
+aoc-core = { path = \"aoc-core\" }
+day1-part1 = { path = \"day1/part1\" }
+day1-part2 = { path = \"day1/part2\" }
+day3-part1 = { path = \"day3/part1\" }
+day3-part2 = { path = \"day3/part2\" }
+num = \"0.4\"
+";
+
+    const RUNNER_MANIFEST: &str = "\
+[dependencies]
+aoc-core.workspace = true
+day1-part1.workspace = true
+day1-part2.workspace = true
+day3-part1.workspace = true
+day3-part2.workspace = true
+
+[features]
+checked = [
+    \"day1-part1/checked\",
+    \"day1-part2/checked\",
+    \"day3-part1/checked\",
+]
+";
+
+    const REGISTRY: &str = "\
+register! {
+    1 => day1_part1 [lines explain], day1_part2 [lines explain];
+    3 => day3_part1 [lines], day3_part2 [lines];
+}
+";
+
+    #[test]
+    fn adds_day_after_the_last_day() {
+        let manifest = add_workspace_dependencies(WORKSPACE_MANIFEST, 4).unwrap();
+        assert!(manifest.contains("day3-part2 = { path = \"day3/part2\" }\nday4-part1 = { path = \"day4/part1\" }\nday4-part2 = { path = \"day4/part2\" }\nnum"));
+
+        let manifest = add_runner_dependencies(RUNNER_MANIFEST, 4).unwrap();
+        assert!(manifest.contains("day3-part2.workspace = true\nday4-part1.workspace = true\nday4-part2.workspace = true\n\n[features]"));
+        assert!(manifest.contains("    \"day3-part1/checked\",\n    \"day4-part1/checked\",\n    \"day4-part2/checked\",\n]"));
+
+        let registry = register(REGISTRY, 4).unwrap();
+        assert!(registry.contains("day3_part2 [lines];\n    4 => day4_part1, day4_part2;\n}\n"));
+    }
+
+    #[test]
+    fn adds_day_to_the_checked_feature_in_order() {
+        let manifest = add_runner_dependencies(RUNNER_MANIFEST, 2).unwrap();
+        assert!(manifest.contains("    \"day1-part2/checked\",\n    \"day2-part1/checked\",\n    \"day2-part2/checked\",\n    \"day3-part1/checked\","));
+        assert!(add_runner_dependencies("[dependencies]\nday1-part1.workspace = true\n", 2).is_err());
+    }
+
+    #[test]
+    fn keeps_days_ordered() {
+        let registry = register("register! {\n    1 => day1_part1, day1_part2;\n    3 => day3_part1, day3_part2;\n}\n", 2).unwrap();
+        assert_eq!(registry, "register! {\n    1 => day1_part1, day1_part2;\n    2 => day2_part1, day2_part2;\n    3 => day3_part1, day3_part2;\n}\n");
+    }
+
+    #[test]
+    fn rejects_existing_days() {
+        assert!(add_workspace_dependencies(WORKSPACE_MANIFEST, 1).is_err());
+        assert!(add_runner_dependencies(RUNNER_MANIFEST, 1).is_err());
+        assert!(register(REGISTRY, 1).is_err());
+        assert!(register("", 4).is_err());
+    }
+
+    #[test]
+    fn creates_day() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(root.join("Cargo.toml"), WORKSPACE_MANIFEST).unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), RUNNER_MANIFEST).unwrap();
+        fs::write(root.join("aoc/src/registry.rs"), REGISTRY).unwrap();
+
+        let files = new_day(&root, 4).unwrap();
+        let manifest = fs::read_to_string(root.join("day4/part1/Cargo.toml")).unwrap();
+        let lib = fs::read_to_string(root.join("day4/part2/src/lib.rs")).unwrap();
+        let main = fs::read_to_string(root.join("day4/part1/src/main.rs")).unwrap();
+        let exists = root.join("day4/input").exists();
+        let again = new_day(&root, 4);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(files.len(), 10);
+        assert!(manifest.contains("name = \"day4-part1\"") && manifest.contains("[features]\n# Never overflow, widening to 128 bits or big integers and reporting overflow as an error\nchecked = []"));
+        assert!(lib.starts_with("use aoc_core::error::ParseError;") && lib.contains("/// Solve part 2 of day 4"));
+        assert!(main.contains("report::main(4, 1, \"Answer\", input_path!(), 1, day4_part1::solve);"));
+        assert!(exists);
+        assert!(again.is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rolls_back_a_failed_day() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-rollback-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(root.join("Cargo.toml"), WORKSPACE_MANIFEST).unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), RUNNER_MANIFEST).unwrap();
+        fs::write(root.join("aoc/src/registry.rs"), REGISTRY).unwrap();
+        // The directory of the day cannot be created through a link that points nowhere
+        std::os::unix::fs::symlink(root.join("missing"), root.join("day4")).unwrap();
+
+        let result = new_day(&root, 4);
+        let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        let runner = fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+        let registry = fs::read_to_string(root.join("aoc/src/registry.rs")).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(result.unwrap_err().starts_with("Could not create"));
+        assert_eq!((workspace.as_str(), runner.as_str(), registry.as_str()), (WORKSPACE_MANIFEST, RUNNER_MANIFEST, REGISTRY));
+    }
+}
@@ -0,0 +1,115 @@
+use std::ffi::OsString;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+
+use clap::Args;
+
+use aoc::answers::{self, Answer, Answers};
+use aoc::{panic_message, registry};
+use crate::run::{read_shared_input, ExecutorArgs};
+
+/// Run the solvers and compare their answers with the recorded answers
+#[derive(Args)]
+pub struct VerifyArgs {
+    /// Day to verify, verifies all days if omitted
+    #[arg(short, long)]
+    day: Option<u32>,
+
+    /// Part to verify, verifies both parts if omitted
+    #[arg(short, long)]
+    part: Option<u32>,
+
+    /// Input file to use instead of the personal input of the day, `-` reads from stdin
+    ///
+    /// Defaults to the AOC_INPUT environment variable if it is set.
+    #[arg(short, long, requires = "day")]
+    input: Option<OsString>,
+
+    /// Manifest containing the recorded answers
+    #[arg(long, default_value = answers::DEFAULT_PATH)]
+    answers: PathBuf,
+
+    /// Record the answers for inputs that do not have a recorded answer yet
+    #[arg(long)]
+    record: bool,
+
+    #[command(flatten)]
+    executor: ExecutorArgs,
+}
+
+/// Result of verifying a single solver
+enum Outcome {
+    /// The answer matches the recorded answer
+    Pass,
+    /// The answer differs from the recorded answer
+    Mismatch(String),
+    /// There is no recorded answer for the input
+    Missing,
+    /// The solver did not produce an answer
+    Fail(String),
+}
+
+/// Verify the selected solvers against the answers manifest
+pub fn verify(args: VerifyArgs) -> Result<(), String> {
+    let solvers = registry::select(args.day, args.part)?;
+    let executor = args.executor.executor();
+    let mut answers = Answers::load(&args.answers)?;
+    let shared_input = read_shared_input(args.input)?;
+
+    let mut failures = 0;
+    let mut recorded = 0;
+    for solver in &solvers {
+        let day_input;
+        let input: &str = match &shared_input {
+            Some(input) => input,
+            None => {
+                day_input = solver.default_source().map().map_err(|err| err.to_string())?;
+                &day_input
+            }
+        };
+        let input_hash = answers::input_hash(input);
+
+        // Solve the puzzle and compare the answer with the recorded answer, reporting a panicking solver as a failure
+        let (answer, outcome) = match panic::catch_unwind(AssertUnwindSafe(|| solver.run(input, executor.as_ref()))) {
+            Ok(Ok(answer)) => {
+                let outcome = match answers.get(solver.day, solver.part, &input_hash) {
+                    Some(expected) if expected == answer => Outcome::Pass,
+                    Some(expected) => Outcome::Mismatch(expected.to_string()),
+                    None => Outcome::Missing,
+                };
+                (answer, outcome)
+            }
+            Ok(Err(err)) => (String::new(), Outcome::Fail(err.to_string())),
+            Err(payload) => (String::new(), Outcome::Fail(panic_message(payload))),
+        };
+
+        let prefix = format!("Day {:>2} part {}:", solver.day, solver.part);
+        match outcome {
+            Outcome::Pass => println!("{} pass      {}", prefix, answer),
+            Outcome::Mismatch(expected) => {
+                failures += 1;
+                println!("{} mismatch  expected {}, got {}", prefix, expected, answer);
+            }
+            Outcome::Missing if args.record => {
+                recorded += 1;
+                println!("{} recorded  {}", prefix, answer);
+                answers.record(Answer { day: solver.day, part: solver.part, input: input_hash, answer });
+            }
+            Outcome::Missing => println!("{} missing   {} (no recorded answer for input {})", prefix, answer, &input_hash[..8]),
+            Outcome::Fail(message) => {
+                failures += 1;
+                println!("{} fail      {}", prefix, message);
+            }
+        }
+    }
+
+    if recorded > 0 {
+        answers.save(&args.answers)?;
+    }
+
+    if failures > 0 {
+        return Err(format!("{} of {} answers could not be verified", failures, solvers.len()));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,274 @@
+use std::env;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use aoc_core::executor::Executor;
+use aoc_core::input::Source;
+use aoc_core::report;
+use clap::Args;
+use notify::{Event, RecursiveMode, Watcher};
+
+use aoc::panic_message;
+use aoc::registry::{self, Solver};
+use aoc::scaffold;
+use crate::run::ExecutorArgs;
+
+// Editors often write a file in several steps, so changes that follow each other this closely are handled at once
+const SETTLE_TIME: Duration = Duration::from_millis(50);
+
+// Environment variable that hands the answers over to the rebuilt runner, as a line of `day part answer` per answer
+const PREVIOUS_ANSWERS: &str = "AOC_WATCH_PREVIOUS";
+
+/// Watch the input and the solution of a day and solve it again whenever either changes
+///
+/// A change to the source of the day or of `aoc-core` rebuilds the runner with cargo and runs the new runner in its
+/// place, which compares its answers with those of the old one.
+#[derive(Args)]
+pub struct WatchArgs {
+    /// Day to watch
+    #[arg(short, long)]
+    day: u32,
+
+    /// Part to solve, solves both parts if omitted
+    #[arg(short, long)]
+    part: Option<u32>,
+
+    /// Input file to watch instead of the personal input of the day
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    #[command(flatten)]
+    executor: ExecutorArgs,
+}
+
+/// Solve the selected solvers on the input and again on every change, until interrupted
+pub fn watch(args: WatchArgs) -> Result<(), String> {
+    let solvers = registry::select(Some(args.day), args.part)?;
+    let executor = args.executor.executor();
+    let path = args.input.unwrap_or_else(|| solvers[0].input.into());
+    // Find the runner before it is rebuilt, since the path of a replaced executable may no longer resolve
+    let runner = env::current_exe().map_err(|err| format!("Could not find the runner: {}", err))?;
+
+    // Watch the directory rather than the file, since editors often replace a file instead of writing to it
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)
+        .map_err(|err| format!("Could not watch {}: {}", path.display(), err))?;
+    let dir = path.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    watcher.watch(dir, RecursiveMode::NonRecursive)
+        .map_err(|err| format!("Could not watch {}: {}", path.display(), err))?;
+    let input = resolve(&path);
+    let sources = sources(&solvers);
+    for source in &sources {
+        watcher.watch(source, RecursiveMode::Recursive)
+            .map_err(|err| format!("Could not watch {}: {}", source.display(), err))?;
+    }
+    eprintln!("Watching {} and the solution, press Ctrl-C to stop", path.display());
+
+    let mut previous = previous_answers(&solvers);
+    let mut previous_hash = None;
+    loop {
+        // Only solve again when the contents changed, editors tend to touch files without changing them
+        // The input is read rather than memory-mapped, since it may be rewritten in place while it is solved
+        match Source::File(path.clone()).read() {
+            Ok(input) => {
+                let hash = report::sha256(&input);
+                if previous_hash.as_ref() != Some(&hash) {
+                    previous_hash = Some(hash);
+                    for (solver, previous) in solvers.iter().zip(&mut previous) {
+                        solve(solver, &input, executor.as_ref(), previous);
+                    }
+                }
+            }
+            Err(err) => eprintln!("error: Could not read {}: {}", path.display(), err),
+        }
+
+        // Wait for a change to the input or the solution and let the changes that follow it settle
+        let mut change = None;
+        while change.is_none() {
+            let event = receiver.recv().map_err(|_| "Stopped watching the input".to_string())?;
+            change = classify(&event, &input, &sources);
+        }
+        while let Ok(event) = receiver.recv_timeout(SETTLE_TIME) {
+            if classify(&event, &input, &sources) == Some(Change::Source) {
+                change = Some(Change::Source);
+            }
+        }
+
+        // Keep solving with the current runner if the changed solution does not build
+        if change == Some(Change::Source) {
+            match rebuild() {
+                Ok(()) => return restart(&runner, &solvers, &previous),
+                Err(err) => eprintln!("error: {}", err),
+            }
+        }
+    }
+}
+
+// What an event of the watcher is about
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Change {
+    Input,
+    Source,
+}
+
+// The source directories of the solvers and of the helpers they share
+fn sources(solvers: &[&Solver]) -> Vec<PathBuf> {
+    let root = Path::new(scaffold::WORKSPACE);
+    let mut sources: Vec<PathBuf> = solvers.iter()
+        .map(|solver| root.join(format!("day{}/part{}/src", solver.day, solver.part)))
+        .collect();
+    // Days that share code between their parts keep it in a common crate, which is watched once per day
+    let mut common: Vec<PathBuf> = solvers.iter()
+        .map(|solver| root.join(format!("day{}/common/src", solver.day)))
+        .filter(|source| source.is_dir())
+        .collect();
+    common.dedup();
+    sources.append(&mut common);
+    sources.push(root.join("aoc-core/src"));
+    sources
+}
+
+// The path with its directory resolved to a canonical path, which also works for a file that was just removed or replaced
+fn resolve(path: &Path) -> PathBuf {
+    let dir = path.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    match (fs::canonicalize(dir), path.file_name()) {
+        (Ok(dir), Some(name)) => dir.join(name),
+        _ => path.to_path_buf(),
+    }
+}
+
+// Whether an event of the watcher is about the input, whose path is resolved, or a source file, if about either
+fn classify(event: &notify::Result<Event>, input: &Path, sources: &[PathBuf]) -> Option<Change> {
+    let event = event.as_ref().ok().filter(|event| !event.kind.is_access())?;
+    if event.paths.iter().any(|changed| changed.extension().is_some_and(|extension| extension == "rs") && sources.iter().any(|source| changed.starts_with(source))) {
+        Some(Change::Source)
+    } else if event.paths.iter().any(|changed| resolve(changed) == input) {
+        Some(Change::Input)
+    } else {
+        None
+    }
+}
+
+// Build the runner again with cargo, using the profile and features of the running runner
+fn rebuild() -> Result<(), String> {
+    eprintln!("Rebuilding the runner");
+    let mut cargo = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    cargo.current_dir(scaffold::WORKSPACE).args(["build", "-p", "aoc"]);
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+    if cfg!(feature = "checked") {
+        cargo.args(["--features", "checked"]);
+    }
+
+    let status = cargo.status().map_err(|err| format!("Could not run cargo: {}", err))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("Could not rebuild the runner, cargo exited with {}", status))
+    }
+}
+
+// Run the rebuilt runner with the same arguments in place of this one, handing over the answers
+fn restart(runner: &Path, solvers: &[&Solver], previous: &[Option<String>]) -> Result<(), String> {
+    let answers: Vec<String> = solvers.iter()
+        .zip(previous)
+        .filter_map(|(solver, answer)| answer.as_ref().map(|answer| format!("{} {} {}", solver.day, solver.part, answer)))
+        .collect();
+    let mut command = Command::new(runner);
+    command.args(env::args_os().skip(1)).env(PREVIOUS_ANSWERS, answers.join("\n"));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        Err(format!("Could not run the rebuilt runner: {}", command.exec()))
+    }
+    #[cfg(not(unix))]
+    {
+        let status = command.status().map_err(|err| format!("Could not run the rebuilt runner: {}", err))?;
+        std::process::exit(status.code().unwrap_or(1))
+    }
+}
+
+// The answers handed over by the runner that was rebuilt, if any
+fn previous_answers(solvers: &[&Solver]) -> Vec<Option<String>> {
+    let handed_over = env::var(PREVIOUS_ANSWERS).unwrap_or_default();
+    solvers.iter()
+        .map(|solver| {
+            let prefix = format!("{} {} ", solver.day, solver.part);
+            handed_over.lines().find_map(|line| line.strip_prefix(&prefix)).map(str::to_string)
+        })
+        .collect()
+}
+
+// Solve the input and print the answer together with how it changed since the previous answer,
+// reporting a panicking solver without ending the watch
+fn solve(solver: &Solver, input: &str, executor: Option<&Executor>, previous: &mut Option<String>) {
+    let (answer, solve_time) = report::time(|| panic::catch_unwind(AssertUnwindSafe(|| solver.run(input, executor))));
+    match answer {
+        Ok(Ok(answer)) => {
+            let change = previous.as_deref().map(|previous| diff(previous, &answer)).unwrap_or_default();
+            println!("Day {:>2} part {}: {:<20} ({:.2?}){}", solver.day, solver.part, answer, solve_time, change);
+            *previous = Some(answer);
+        }
+        // Keep the previous answer, so the next answer is compared with the last input that could be solved
+        Ok(Err(err)) => eprintln!("Day {:>2} part {}: {}", solver.day, solver.part, err.diagnostic()),
+        Err(payload) => eprintln!("Day {:>2} part {}: the solver panicked: {}", solver.day, solver.part, panic_message(payload)),
+    }
+}
+
+// Describe how an answer differs from the previous answer, with the difference if both are integers
+fn diff(previous: &str, answer: &str) -> String {
+    if previous == answer {
+        return " unchanged".to_string();
+    }
+
+    let difference = previous.parse::<i128>().ok()
+        .zip(answer.parse::<i128>().ok())
+        .and_then(|(previous, answer)| answer.checked_sub(previous));
+    match difference {
+        Some(difference) => format!(" was {} ({:+})", previous, difference),
+        None => format!(" was {}", previous),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diffs() {
+        assert_eq!(diff("142", "142"), " unchanged");
+        assert_eq!(diff("142", "150"), " was 142 (+8)");
+        assert_eq!(diff("150", "142"), " was 150 (-8)");
+        assert_eq!(diff("abc", "142"), " was abc");
+    }
+
+    #[test]
+    fn classifies_changes() {
+        let solvers = registry::select(Some(1), Some(2)).unwrap();
+        let sources = sources(&solvers);
+        let event = |path: PathBuf| Ok(Event::new(notify::EventKind::Any).add_path(path));
+        let root = Path::new(scaffold::WORKSPACE);
+        let input = resolve(&root.join("day1/input"));
+
+        assert_eq!(classify(&event(root.join("day1/part2/src/lib.rs")), &input, &sources), Some(Change::Source));
+        assert_eq!(classify(&event(root.join("aoc-core/src/parse.rs")), &input, &sources), Some(Change::Source));
+        assert_eq!(classify(&event(root.join("day1/input")), &input, &sources), Some(Change::Input));
+        assert_eq!(classify(&event(root.join("day1/part1/src/lib.rs")), &input, &sources), None);
+        assert_eq!(classify(&event(root.join("day1/part2/src/.lib.rs.swp")), &input, &sources), None);
+        // Only the watched input is the input, not every other file with the same name
+        assert_eq!(classify(&event(root.join("day2/input")), &input, &sources), None);
+        assert_eq!(classify(&event(root.join("day1/part1/input")), &input, &sources), None);
+
+        // Both parts of day 6 share the code in its common crate
+        let solvers = registry::select(Some(6), None).unwrap();
+        let sources = super::sources(&solvers);
+        assert_eq!(sources.iter().filter(|source| source.ends_with("day6/common/src")).count(), 1);
+        assert_eq!(classify(&event(root.join("day6/common/src/lib.rs")), &resolve(&root.join("day6/input")), &sources), Some(Change::Source));
+    }
+}
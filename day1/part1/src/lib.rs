@@ -0,0 +1,125 @@
+use std::io::{self, BufRead};
+
+use aoc_core::error::ParseError;
+use aoc_core::executor::Executor;
+use aoc_core::explain::{self, Explanation};
+use aoc_core::parse::RADIX;
+use aoc_core::solver::LineSolver;
+use aoc_core::total::{FromValue, Total};
+
+use tracing::info_span;
+
+// Find the first and the last digit of a line
+fn digits(line: &str) -> Result<(u32, u32), ParseError> {
+    let missing_digit = || ParseError::new(line, line.len(), "a digit");
+
+    // Get the first digit
+    let first = line.chars()
+        .find_map(|c| c.to_digit(RADIX))
+        .ok_or_else(missing_digit)?;
+    
+    // Get the last digit
+    let last = line.chars().rev()
+        .find_map(|c| c.to_digit(RADIX))
+        .ok_or_else(missing_digit)?;
+
+    Ok((first, last))
+}
+
+/// Function that processes each line
+pub fn process_line(line: &str) -> Result<u32, ParseError> {
+    // Multiply the first digit by the RADIX and add the last digit
+    let (first, last) = digits(line)?;
+    Ok(first * RADIX + last)
+}
+
+/// The first and last digit and the calibration value of every line in the input
+pub fn explain(input: &str) -> Result<Vec<Explanation>, ParseError> {
+    explain::lines(input, |line| {
+        let (first, last) = digits(line)?;
+        Ok(Explanation::new().number("first", first).number("last", last).number("value", first * RADIX + last))
+    })
+}
+
+// Calibration values are summed in 32 bits, or in 128 bits with the `checked` feature, and checked for overflow
+#[cfg(not(feature = "checked"))]
+type Sum = aoc_core::total::Checked<u32>;
+#[cfg(feature = "checked")]
+type Sum = aoc_core::total::Checked<u128>;
+
+/// Type of the answer, which never overflows with the `checked` feature
+pub type Answer = <Sum as Total>::Answer;
+
+/// Sums the calibration values of all lines
+pub struct Calibration;
+
+impl LineSolver for Calibration {
+    type Accumulator = Sum;
+    type Answer = Answer;
+
+    fn identity(&self) -> Sum {
+        Sum::zero()
+    }
+
+    fn map(&self, _: usize, line: &str) -> Result<Sum, ParseError> {
+        process_line(line).map(Sum::of)
+    }
+
+    fn combine(&self, a: Sum, b: Sum) -> Sum {
+        a.add(b)
+    }
+
+    fn finish(&self, sum: Sum) -> Result<Answer, ParseError> {
+        sum.answer()
+    }
+}
+
+/// The executor the day runs with by default
+pub fn executor() -> Executor {
+    Executor::default()
+}
+
+/// Sum the calibration values of all lines in the input
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    solve_with(input, &executor())
+}
+
+/// Sum the calibration values of all lines in the input, processing the lines with the given executor
+pub fn solve_with(input: &str, executor: &Executor) -> Result<Answer, ParseError> {
+    // Process the lines and sum the calibration values
+    info_span!("solve").in_scope(|| executor.solve(&Calibration, input))
+}
+
+/// Sum the calibration values of all lines in the input, processing the lines with the given executor as they are read from the reader
+pub fn solve_reader(reader: impl BufRead + Send, executor: &Executor) -> io::Result<Answer> {
+    info_span!("solve").in_scope(|| executor.solve_reader(&Calibration, reader))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(142));
+    }
+
+    #[test]
+    fn explain_example() {
+        let explanations = explain(EXAMPLE).unwrap();
+        assert_eq!(explanations[2], Explanation::new().number("first", 1).number("last", 5).number("value", 15));
+        assert_eq!(explain("1\nabc\n").unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(55712));
+    }
+}
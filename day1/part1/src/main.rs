@@ -1,40 +1,7 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-use rayon::prelude::*;
-
-const RADIX: u32 = 10;
-
-// Function that processes each line
-fn process_line(line: String) -> u32 {
-    let mut result = 0;
-
-    // Get the first digit and multiply it by the RADIX and add it to result
-    result += line.chars()
-        .find_map(|c| c.to_digit(RADIX))
-        .unwrap() * RADIX;
-    
-    // Get the last digit and add it to result
-    result += line.chars().rev()
-        .find_map(|c| c.to_digit(RADIX))
-        .unwrap();
-
-    result
-}
+use aoc_core::{input_path, report};
 
 fn main() {
-    // Open the input file
-    let file = File::open("input").expect("Could not open file \"input\" relative to program");
-
-    // Process the lines and sum the calibration values
-    let total_result: u32 = BufReader::new(file)
-        .lines()
-        .par_bridge()
-        .map(|line| line.ok())
-        .while_some()
-        .map(process_line)
-        .sum();
-
-    // Print the final result
-    println!("Summed calibration values: {}", total_result);
-}
\ No newline at end of file
+    // Solve the input from the first argument, AOC_INPUT or the input file of the day,
+    // and print the answer, or the answer with its timings as json when given `--format json`
+    report::main(1, 1, "Summed calibration values", input_path!(), day1_part1::executor().threads(), day1_part1::solve);
+}
@@ -0,0 +1,156 @@
+use std::io::{self, BufRead};
+use std::sync::LazyLock;
+
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, Anchored, Input, Match, StartKind};
+use aoc_core::error::ParseError;
+use aoc_core::executor::Executor;
+use aoc_core::explain::{self, Explanation};
+use aoc_core::solver::LineSolver;
+use aoc_core::total::{FromValue, Total};
+use tracing::info_span;
+
+// Aho-Corasick trie for matching digits and their words, built once and shared by all lines
+static AUTOMATON: LazyLock<AhoCorasick> = LazyLock::new(|| {
+    // Define the words to be detected
+    let words = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+    // Support anchored searches as well to match at a given position
+    AhoCorasickBuilder::new()
+        .start_kind(StartKind::Both)
+        .build(words)
+        .expect("Should be able to build aho-corasick trie")
+});
+
+// Find the first and the last match of a digit or its word in a line
+fn matches(line: &str) -> Result<(Match, Option<Match>), ParseError> {
+    let automaton = &*AUTOMATON;
+
+    // Get the first match of the Aho-Corasick on the string
+    let Some(first) = automaton.find(line) else {
+        return Err(ParseError::new(line, line.len(), "a digit or a digit spelled out"));
+    };
+
+    // Get the last match by trying to match at every position from the end of the line, since words like "eightwo" overlap
+    let last = (0..line.len())
+        .rev()
+        .find_map(|start| automaton.find(Input::new(line).range(start..).anchored(Anchored::Yes)));
+
+    Ok((first, last))
+}
+
+// Convert the matched pattern index to the relevant integer
+fn digit(mat: Match) -> u32 {
+    mat.pattern().as_u32() % 9 + 1
+}
+
+/// Function that processes each line
+pub fn process_line(line: &str) -> Result<u32, ParseError> {
+    let (first, last) = matches(line)?;
+    // Multiply the first digit by 10 and add the last one
+    Ok(digit(first) * 10 + last.map_or(0, digit))
+}
+
+/// The first and last match and the calibration value of every line in the input
+pub fn explain(input: &str) -> Result<Vec<Explanation>, ParseError> {
+    explain::lines(input, |line| {
+        let (first, last) = matches(line)?;
+        Ok(Explanation::new()
+            .text("first", &line[first.range()])
+            .text("last", last.map_or("", |mat| &line[mat.range()]))
+            .number("value", digit(first) * 10 + last.map_or(0, digit)))
+    })
+}
+
+// Calibration values are summed in 32 bits, or in 128 bits with the `checked` feature, and checked for overflow
+#[cfg(not(feature = "checked"))]
+type Sum = aoc_core::total::Checked<u32>;
+#[cfg(feature = "checked")]
+type Sum = aoc_core::total::Checked<u128>;
+
+/// Type of the answer, which never overflows with the `checked` feature
+pub type Answer = <Sum as Total>::Answer;
+
+/// Sums the calibration values of all lines, counting spelled out digits
+pub struct Calibration;
+
+impl LineSolver for Calibration {
+    type Accumulator = Sum;
+    type Answer = Answer;
+
+    fn identity(&self) -> Sum {
+        Sum::zero()
+    }
+
+    fn map(&self, _: usize, line: &str) -> Result<Sum, ParseError> {
+        process_line(line).map(Sum::of)
+    }
+
+    fn combine(&self, a: Sum, b: Sum) -> Sum {
+        a.add(b)
+    }
+
+    fn finish(&self, sum: Sum) -> Result<Answer, ParseError> {
+        sum.answer()
+    }
+}
+
+/// The executor the day runs with by default
+pub fn executor() -> Executor {
+    Executor::default()
+}
+
+/// Sum the calibration values of all lines in the input, where digits may also be spelled out
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    solve_with(input, &executor())
+}
+
+/// Sum the calibration values of all lines in the input, where digits may also be spelled out, processing the lines with the given executor
+pub fn solve_with(input: &str, executor: &Executor) -> Result<Answer, ParseError> {
+    // Process the lines and sum the calibration values
+    info_span!("solve").in_scope(|| executor.solve(&Calibration, input))
+}
+
+/// Sum the calibration values of all lines in the input, where digits may also be spelled out, processing the lines with the given executor as they are read from the reader
+pub fn solve_reader(reader: impl BufRead + Send, executor: &Executor) -> io::Result<Answer> {
+    info_span!("solve").in_scope(|| executor.solve_reader(&Calibration, reader))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(281));
+    }
+
+    #[test]
+    fn overlapping_last_word() {
+        assert_eq!(process_line("1eightwo"), Ok(12));
+        assert_eq!(process_line("oneight"), Ok(18));
+        assert_eq!(process_line("twone"), Ok(21));
+        assert_eq!(process_line("sevenine"), Ok(79));
+    }
+
+    #[test]
+    fn explain_example() {
+        let explanations = explain(EXAMPLE).unwrap();
+        assert_eq!(explanations[1], Explanation::new().text("first", "eight").text("last", "three").number("value", 83));
+        assert_eq!(explain("oneight\n").unwrap()[0], Explanation::new().text("first", "one").text("last", "eight").number("value", 18));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(55413));
+    }
+}
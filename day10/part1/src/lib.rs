@@ -0,0 +1,113 @@
+use aoc_core::error::ParseError;
+use aoc_core::executor::Executor;
+use aoc_core::grid::{Grid, Position};
+
+use tracing::info_span;
+
+// Function that tells if a character can have a left connection
+fn map_char_to_left(c: char) -> bool {
+    matches!(c, '-' | 'J' | '7' | 'S')
+}
+
+// Function that tells if a character can have a right connection
+fn map_char_to_right(c: char) -> bool {
+    matches!(c, '-' | 'L' | 'F' | 'S')
+}
+
+// Function that tells if a character can have a top connection
+fn map_char_to_top(c: char) -> bool {
+    matches!(c, '|' | 'L' | 'J' | 'S')
+}
+
+// Function that tells if a character can have a bottom connection
+fn map_char_to_bottom(c: char) -> bool {
+    matches!(c, '|' | '7' | 'F' | 'S')
+}
+
+// Function that parses each tile, which has to be a pipe, ground or the start
+fn parse_tile(c: char) -> Option<char> {
+    matches!(c, '|' | '-' | 'L' | 'J' | '7' | 'F' | '.' | 'S').then_some(c)
+}
+
+// Function that tells if two neighbouring tiles connect to each other
+fn connected(grid: &Grid<char>, from: Position, to: Position) -> bool {
+    if from.0 == to.0 {
+        // The left tile has to connect to the right and the right tile to the left
+        let (left, right) = if from.1 < to.1 { (from, to) } else { (to, from) };
+        map_char_to_right(grid[left]) && map_char_to_left(grid[right])
+    } else {
+        // The top tile has to connect to the bottom and the bottom tile to the top
+        let (top, bottom) = if from.0 < to.0 { (from, to) } else { (to, from) };
+        map_char_to_bottom(grid[top]) && map_char_to_top(grid[bottom])
+    }
+}
+
+/// The executor the day runs with by default
+pub fn executor() -> Executor {
+    Executor::sequential()
+}
+
+/// Count the number of steps along the loop from the start to the furthest pipe
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    solve_with(input, &executor())
+}
+
+/// Count the number of steps along the loop from the start to the furthest pipe, processing the lines with the given executor
+pub fn solve_with(input: &str, executor: &Executor) -> Result<usize, ParseError> {
+    // Parse the lines to a grid of tiles and find the start position
+    let parse = info_span!("parse").entered();
+    let grid = Grid::parse(input, executor, "a pipe, ground or the start", parse_tile)?;
+    let start_position = grid.position(|&tile| tile == 'S')
+        .ok_or_else(|| ParseError::end_of_input(grid.height(), "a start tile S"))?;
+
+    drop(parse);
+    let _solve = info_span!("solve").entered();
+
+    // Travel along the loop that the start node is connected to and count the number of pipes
+    let in_loop = grid.flood_fill(start_position, |from, to| connected(&grid, from, to));
+    let number_of_pipes = in_loop.iter().filter(|&(_, &part_of_loop)| part_of_loop).count();
+
+    // The furthest pipe is halfway along the loop
+    Ok(number_of_pipes / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+";
+
+    const EXAMPLE_2: &str = "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+
+    #[test]
+    fn example_1() {
+        assert_eq!(solve(EXAMPLE_1), Ok(4));
+    }
+
+    #[test]
+    fn example_2() {
+        assert_eq!(solve(EXAMPLE_2), Ok(8));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(6875));
+    }
+
+    #[test]
+    fn missing_start() {
+        assert_eq!(solve("F7\nLJ\n"), Err(ParseError::end_of_input(2, "a start tile S")));
+    }
+}
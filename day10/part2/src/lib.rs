@@ -0,0 +1,148 @@
+use aoc_core::error::ParseError;
+use aoc_core::executor::Executor;
+use aoc_core::grid::{Grid, Position};
+
+use tracing::info_span;
+
+// Function that tells if a character can have a left connection
+fn map_char_to_left(c: char) -> bool {
+    matches!(c, '-' | 'J' | '7' | 'S')
+}
+
+// Function that tells if a character can have a right connection
+fn map_char_to_right(c: char) -> bool {
+    matches!(c, '-' | 'L' | 'F' | 'S')
+}
+
+// Function that tells if a character can have a top connection
+fn map_char_to_top(c: char) -> bool {
+    matches!(c, '|' | 'L' | 'J' | 'S')
+}
+
+// Function that tells if a character can have a bottom connection
+fn map_char_to_bottom(c: char) -> bool {
+    matches!(c, '|' | '7' | 'F' | 'S')
+}
+
+// Function that parses each tile, which has to be a pipe, ground or the start
+fn parse_tile(c: char) -> Option<char> {
+    matches!(c, '|' | '-' | 'L' | 'J' | '7' | 'F' | '.' | 'S').then_some(c)
+}
+
+// Function that tells if two neighbouring tiles connect to each other
+fn connected(grid: &Grid<char>, from: Position, to: Position) -> bool {
+    if from.0 == to.0 {
+        // The left tile has to connect to the right and the right tile to the left
+        let (left, right) = if from.1 < to.1 { (from, to) } else { (to, from) };
+        map_char_to_right(grid[left]) && map_char_to_left(grid[right])
+    } else {
+        // The top tile has to connect to the bottom and the bottom tile to the top
+        let (top, bottom) = if from.0 < to.0 { (from, to) } else { (to, from) };
+        map_char_to_bottom(grid[top]) && map_char_to_top(grid[bottom])
+    }
+}
+
+/// The executor the day runs with by default
+pub fn executor() -> Executor {
+    Executor::sequential()
+}
+
+/// Count the number of tiles enclosed by the loop
+pub fn solve(input: &str) -> Result<usize, ParseError> {
+    solve_with(input, &executor())
+}
+
+/// Count the number of tiles enclosed by the loop, processing the lines with the given executor
+pub fn solve_with(input: &str, executor: &Executor) -> Result<usize, ParseError> {
+    // Parse the lines to a grid of tiles and find the start position
+    let parse = info_span!("parse").entered();
+    let grid = Grid::parse(input, executor, "a pipe, ground or the start", parse_tile)?;
+    let start_position = grid.position(|&tile| tile == 'S')
+        .ok_or_else(|| ParseError::end_of_input(grid.height(), "a start tile S"))?;
+
+    drop(parse);
+    let _solve = info_span!("solve").entered();
+
+    // Travel along the loop that the start node is connected to
+    let in_loop = grid.flood_fill(start_position, |from, to| connected(&grid, from, to));
+
+    // Count the tiles inside the loop row by row
+    // Use vertical connections on parts of the loop to idicate whether we have passed a boundary
+    let mut inside = 0;
+    for (i, loop_row) in in_loop.rows().enumerate() {
+        let mut inside_loop = false;
+        for (j, &part_of_loop) in loop_row.iter().enumerate() {
+            if !part_of_loop && inside_loop {
+                inside += 1;
+            } else if part_of_loop && i < grid.height() - 1 && connected(&grid, (i, j), (i + 1, j)) {
+                inside_loop = !inside_loop;
+            }
+        }
+    }
+
+    // The tiles inside the loop make up the enclosed area
+    Ok(inside)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+
+    const EXAMPLE_2: &str = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+
+    const EXAMPLE_3: &str = "\
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
+
+    #[test]
+    fn example_1() {
+        assert_eq!(solve(EXAMPLE_1), Ok(4));
+    }
+
+    #[test]
+    fn example_2() {
+        assert_eq!(solve(EXAMPLE_2), Ok(8));
+    }
+
+    #[test]
+    fn example_3() {
+        assert_eq!(solve(EXAMPLE_3), Ok(10));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(471));
+    }
+}
@@ -0,0 +1,106 @@
+use aoc_core::error::ParseError;
+use aoc_core::executor::Executor;
+use aoc_core::grid::Grid;
+use aoc_core::total::{FromValue, Total};
+
+use tracing::info_span;
+
+// Function that parses each square, which has to be a galaxy or empty space
+fn parse_square(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+// Distances are summed in a usize, or in 128 bits with the `checked` feature, and checked for overflow
+#[cfg(not(feature = "checked"))]
+type Sum = aoc_core::total::Checked<usize>;
+#[cfg(feature = "checked")]
+type Sum = aoc_core::total::Checked<u128>;
+
+/// Type of the answer, which never overflows with the `checked` feature
+pub type Answer = <Sum as Total>::Answer;
+
+/// The executor the day runs with by default
+pub fn executor() -> Executor {
+    Executor::sequential()
+}
+
+/// Sum the shortest distances between all pairs of galaxies after replacing the empty rows and columns by a million
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    solve_with(input, &executor())
+}
+
+/// Sum the shortest distances between all pairs of galaxies, processing the lines and galaxies with the given executor
+pub fn solve_with(input: &str, executor: &Executor) -> Result<Answer, ParseError> {
+    // Parse the lines to a grid indicating the positions of galaxies
+    let parse = info_span!("parse").entered();
+    let universe = Grid::parse(input, executor, "a galaxy or empty space", parse_square)?;
+
+    drop(parse);
+    let _solve = info_span!("solve").entered();
+
+    // Find which rows and columns are empty
+    let empty_rows: Vec<bool> = universe.rows()
+        .map(|universe_row| !universe_row.contains(&true))
+        .collect();
+    let empty_columns: Vec<bool> = universe.columns()
+        .map(|mut universe_column| !universe_column.any(|&square| square))
+        .collect();
+
+    // Convert grid with galaxies to array with galaxy position
+    // While accounting for the extra offset of empty rows and colums
+    // 1000000 - 1 = 999999
+    let mut galaxies = vec![];
+    let mut extra_height = 0;
+    universe.rows().enumerate().for_each(|(row, universe_row)| {
+        if empty_rows[row] {
+            extra_height += 999999;
+        }
+        let mut extra_width = 0;
+        universe_row.iter().enumerate().for_each(|(column, &square)| {
+            if empty_columns[column] {
+                extra_width += 999999;
+            }
+            if square {
+                galaxies.push((row + extra_height, column + extra_width));
+            }
+        })
+    });
+
+    // Sum distances between all pairs of galaxies
+    // Pair every galaxy with the galaxies after it so each pair is counted once
+    executor.map_reduce(&galaxies, Sum::zero, |i, &(row, column)| Sum::sum(galaxies[i + 1..].iter()
+        .map(|&(other_row, other_column)| row.abs_diff(other_row) + column.abs_diff(other_column))), Total::add)
+        .answer()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(82000210));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(377318892554));
+    }
+}
@@ -0,0 +1,172 @@
+use std::io::{self, BufRead};
+
+use aoc_core::error::ParseError;
+use aoc_core::executor::Executor;
+use aoc_core::explain::{self, Explanation};
+use aoc_core::parse::Cursor;
+use aoc_core::solver::LineSolver;
+use aoc_core::total::{FromValue, Total};
+
+use tracing::info_span;
+
+const REDS: u32 = 12;
+const GREENS: u32 = 13;
+const BLUES: u32 = 14;
+
+// Read the color following a number of cubes, returning its first letter
+fn next_color(cursor: &mut Cursor) -> Result<char, ParseError> {
+    cursor.skip_whitespace();
+    let offset = cursor.offset();
+    match cursor.word().unwrap_or_default().trim_end_matches([',', ';']) {
+        "red" => Ok('r'),
+        "green" => Ok('g'),
+        "blue" => Ok('b'),
+        _ => Err(cursor.error_at(offset, "red, green or blue")),
+    }
+}
+
+// Read the game number and the first number of cubes that is too high for its color, if any
+fn check_game(line: &str) -> Result<(u32, Option<(u32, char)>), ParseError> {
+    // Start processing lines
+    let mut cursor = Cursor::new(line);
+
+    // Get the game number
+    cursor.tag("Game")?;
+    let game_number: u32 = cursor.number()?;
+    cursor.tag(":")?;
+
+    // While we have numbers in the line, parse the whole game before checking it
+    let mut too_many = None;
+    while !cursor.at_end() {
+        let number: u32 = cursor.number()?;
+        // Match the first letter of the color after the number and keep the first number that is too high for its color
+        let color = next_color(&mut cursor)?;
+        let limit = match color {
+            'r' => REDS,
+            'g' => GREENS,
+            _ => BLUES,
+        };
+        if number > limit && too_many.is_none() {
+            too_many = Some((number, color));
+        }
+    }
+
+    Ok((game_number, too_many))
+}
+
+/// Function that processes each line
+pub fn process_line(line: &str) -> Result<u32, ParseError> {
+    // If nothing was wrong we return the game number to add to the total, and 0 otherwise
+    match check_game(line)? {
+        (game_number, None) => Ok(game_number),
+        (_, Some(_)) => Ok(0),
+    }
+}
+
+/// The game number, the first number of cubes that makes it impossible and the value of every game in the input
+pub fn explain(input: &str) -> Result<Vec<Explanation>, ParseError> {
+    explain::lines(input, |line| {
+        let (game_number, too_many) = check_game(line)?;
+        let too_many = too_many.map(|(number, color)| match color {
+            'r' => format!("{} red", number),
+            'g' => format!("{} green", number),
+            _ => format!("{} blue", number),
+        });
+        Ok(Explanation::new()
+            .number("game", game_number)
+            .text("too many", too_many.as_deref().unwrap_or(""))
+            .number("value", if too_many.is_none() { game_number } else { 0 }))
+    })
+}
+
+// Game numbers are summed in 32 bits, or in 128 bits with the `checked` feature, and checked for overflow
+#[cfg(not(feature = "checked"))]
+type Sum = aoc_core::total::Checked<u32>;
+#[cfg(feature = "checked")]
+type Sum = aoc_core::total::Checked<u128>;
+
+/// Type of the answer, which never overflows with the `checked` feature
+pub type Answer = <Sum as Total>::Answer;
+
+/// Sums the game numbers of all games that are possible with the available cubes
+pub struct PossibleGames;
+
+impl LineSolver for PossibleGames {
+    type Accumulator = Sum;
+    type Answer = Answer;
+
+    fn identity(&self) -> Sum {
+        Sum::zero()
+    }
+
+    fn map(&self, _: usize, line: &str) -> Result<Sum, ParseError> {
+        process_line(line).map(Sum::of)
+    }
+
+    fn combine(&self, a: Sum, b: Sum) -> Sum {
+        a.add(b)
+    }
+
+    fn finish(&self, sum: Sum) -> Result<Answer, ParseError> {
+        sum.answer()
+    }
+}
+
+/// The executor the day runs with by default
+pub fn executor() -> Executor {
+    Executor::default()
+}
+
+/// Sum the game numbers of all games that are possible with the available cubes
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    solve_with(input, &executor())
+}
+
+/// Sum the game numbers of all games that are possible with the available cubes, processing the lines with the given executor
+pub fn solve_with(input: &str, executor: &Executor) -> Result<Answer, ParseError> {
+    // Process the lines and sum the possible games
+    info_span!("solve").in_scope(|| executor.solve(&PossibleGames, input))
+}
+
+/// Sum the game numbers of all games that are possible with the available cubes, processing the lines with the given executor as they are read from the reader
+pub fn solve_reader(reader: impl BufRead + Send, executor: &Executor) -> io::Result<Answer> {
+    info_span!("solve").in_scope(|| executor.solve_reader(&PossibleGames, reader))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(8));
+    }
+
+    #[test]
+    fn explain_example() {
+        let explanations = explain(EXAMPLE).unwrap();
+        assert_eq!(explanations[0], Explanation::new().number("game", 1).text("too many", "").number("value", 1));
+        assert_eq!(explanations[2], Explanation::new().number("game", 3).text("too many", "20 red").number("value", 0));
+    }
+
+    #[test]
+    fn invalid_after_too_many() {
+        assert_eq!(
+            solve("Game 3: 20 red, x blue").unwrap_err().to_string(),
+            "line 1, column 17: expected a number, found 'x'"
+        );
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(2348));
+    }
+}
@@ -0,0 +1,170 @@
+use std::cmp::max;
+use std::io::{self, BufRead};
+
+use aoc_core::error::ParseError;
+use aoc_core::executor::Executor;
+use aoc_core::explain::{self, Explanation};
+use aoc_core::parse::Cursor;
+use aoc_core::solver::LineSolver;
+use aoc_core::total::{FromValue, Total};
+
+use tracing::info_span;
+
+// Read the color following a number of cubes, returning its first letter
+fn next_color(cursor: &mut Cursor) -> Result<char, ParseError> {
+    cursor.skip_whitespace();
+    let offset = cursor.offset();
+    match cursor.word().unwrap_or_default().trim_end_matches([',', ';']) {
+        "red" => Ok('r'),
+        "green" => Ok('g'),
+        "blue" => Ok('b'),
+        _ => Err(cursor.error_at(offset, "red, green or blue")),
+    }
+}
+
+/// Power of a set of cubes, multiplied in 128 bits where the product of three u32 always fits
+pub type Power = u128;
+
+// Read the highest number of reds, greens and blues of a game, which is the minimal set of cubes it is possible with
+fn minimal_set(line: &str) -> Result<(u32, u32, u32), ParseError> {
+    // Start processing lines
+    let mut cursor = Cursor::new(line);
+    let mut reds = 0;
+    let mut greens = 0;
+    let mut blues = 0;
+
+    // Skip the game_number
+    cursor.tag("Game")?;
+    let _: u32 = cursor.number()?;
+    cursor.tag(":")?;
+
+    // While we have numbers in the line
+    while !cursor.at_end() {
+        let number: u32 = cursor.number()?;
+        // Match the first letter of the color after the number and keep the highest number of each color
+        match next_color(&mut cursor)? {
+            'r' => {reds = max(reds, number)},
+            'g' => {greens = max(greens, number)},
+            _ => {blues = max(blues, number)},
+        }
+    }
+
+    Ok((reds, greens, blues))
+}
+
+/// Function that processes each line
+pub fn process_line(line: &str) -> Result<Power, ParseError> {
+    // If nothing was wrong we return the number of reds, greens and blues multiplied together
+    let (reds, greens, blues) = minimal_set(line)?;
+    Ok(reds as Power * greens as Power * blues as Power)
+}
+
+/// The highest number of cubes of each color and the power of every game in the input
+pub fn explain(input: &str) -> Result<Vec<Explanation>, ParseError> {
+    explain::lines(input, |line| {
+        let (reds, greens, blues) = minimal_set(line)?;
+        Ok(Explanation::new()
+            .number("red", reds)
+            .number("green", greens)
+            .number("blue", blues)
+            .number("power", reds as Power * greens as Power * blues as Power))
+    })
+}
+
+// Powers are summed in 32 bits, or in 128 bits with the `checked` feature, and checked for overflow
+#[cfg(not(feature = "checked"))]
+type Sum = aoc_core::total::Checked<u32>;
+#[cfg(feature = "checked")]
+type Sum = aoc_core::total::Checked<u128>;
+
+/// Type of the answer, which never overflows with the `checked` feature
+pub type Answer = <Sum as Total>::Answer;
+
+/// Sums the power of the minimal set of cubes of all games
+pub struct CubePowers;
+
+impl LineSolver for CubePowers {
+    type Accumulator = Sum;
+    type Answer = Answer;
+
+    fn identity(&self) -> Sum {
+        Sum::zero()
+    }
+
+    fn map(&self, _: usize, line: &str) -> Result<Sum, ParseError> {
+        process_line(line).map(Sum::of)
+    }
+
+    fn combine(&self, a: Sum, b: Sum) -> Sum {
+        a.add(b)
+    }
+
+    fn finish(&self, sum: Sum) -> Result<Answer, ParseError> {
+        sum.answer()
+    }
+}
+
+/// The executor the day runs with by default
+pub fn executor() -> Executor {
+    Executor::default()
+}
+
+/// Sum the power of the minimal set of cubes of all games
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    solve_with(input, &executor())
+}
+
+/// Sum the power of the minimal set of cubes of all games, processing the lines with the given executor
+pub fn solve_with(input: &str, executor: &Executor) -> Result<Answer, ParseError> {
+    // Process the lines and sum the power of sets
+    info_span!("solve").in_scope(|| executor.solve(&CubePowers, input))
+}
+
+/// Sum the power of the minimal set of cubes of all games, processing the lines with the given executor as they are read from the reader
+pub fn solve_reader(reader: impl BufRead + Send, executor: &Executor) -> io::Result<Answer> {
+    info_span!("solve").in_scope(|| executor.solve_reader(&CubePowers, reader))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(2286));
+    }
+
+    #[test]
+    fn explain_example() {
+        let explanations = explain(EXAMPLE).unwrap();
+        assert_eq!(explanations[0], Explanation::new().number("red", 4).number("green", 2).number("blue", 6).number("power", 48));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(76008));
+    }
+
+    #[cfg(not(feature = "checked"))]
+    #[test]
+    fn powers_overflow() {
+        let game = "Game 1: 4000000000 red, 4000000000 green, 4000000000 blue";
+        assert_eq!(solve(&format!("{}\n", game)), Err(ParseError::overflow("a total that fits in u32")));
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn powers_beyond_32_bits() {
+        // The power of a single game does not fit in 32 bits, nor does the sum of the powers
+        let game = "Game 1: 4000000000 red, 4000000000 green, 4000000000 blue";
+        assert_eq!(solve(&format!("{}\n{}\n", game, game)), Ok(2 * 4_000_000_000_u128.pow(3)));
+    }
+}
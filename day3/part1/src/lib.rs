@@ -0,0 +1,202 @@
+use std::io;
+use std::path::Path;
+
+use aoc_core::chunks::{self, ChunkLines, ChunkReader};
+use aoc_core::error::{first_error, ParseError};
+use aoc_core::executor::{Executor, Strategy};
+use aoc_core::grid::Grid;
+use aoc_core::int;
+use aoc_core::total::{FromValue, Total};
+
+use tracing::info_span;
+
+// Whether a cell holds a symbol, where the digits of other numbers are no symbols
+fn is_symbol(c: u8) -> bool {
+    c != b'.' && !c.is_ascii_digit()
+}
+
+// Part numbers are added up in 32 bits, or in 128 bits with the `checked` feature, and checked for overflow
+#[cfg(not(feature = "checked"))]
+type Sum = aoc_core::total::Checked<u32>;
+#[cfg(feature = "checked")]
+type Sum = aoc_core::total::Checked<u128>;
+
+/// Type of the answer, which never overflows with the `checked` feature
+pub type Answer = <Sum as Total>::Answer;
+
+/// Function that processes each row of the schematic, failing with the column of a number that does not fit
+fn process_row(schematic: &Grid<u8>, row: usize) -> Result<Sum, usize> {
+    let mut result = Sum::zero();
+    let mut column = 0;
+    let cells = schematic.row(row);
+
+    while column < cells.len() {
+        // Skip to the start of the next number
+        if !cells[column].is_ascii_digit() {
+            column += 1;
+            continue;
+        }
+
+        // Read the number and check whether any of its digits touches a symbol, including diagonally
+        let (number, length) = int::number::<u32>(&cells[column..]).map_err(|_| column)?;
+        let part_number = (column..column + length)
+            .any(|digit| schematic.neighbours8((row, digit)).any(|position| is_symbol(schematic[position])));
+
+        if part_number {
+            result = result.add(Sum::of(number));
+        }
+        column += length;
+    }
+
+    Ok(result)
+}
+
+/// The executor the day runs with by default
+pub fn executor() -> Executor {
+    Executor::new(Strategy::Chunked, None)
+}
+
+/// Sum all part numbers, i.e. numbers adjacent to a symbol, in the engine schematic
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    solve_with(input, &executor())
+}
+
+/// Sum all part numbers, i.e. numbers adjacent to a symbol, in the engine schematic, processing the input in chunks of lines with the given executor
+pub fn solve_with(input: &str, executor: &Executor) -> Result<Answer, ParseError> {
+    // Columns are compared between lines, which requires every character to be a single byte
+    let parse = info_span!("parse").entered();
+    if let Some((i, line)) = input.lines().enumerate().find(|(_, line)| !line.is_ascii()) {
+        let offset = line.find(|c: char| !c.is_ascii()).unwrap_or_default();
+        return Err(ParseError::new(line, offset, "an ASCII character").at_line(i));
+    }
+
+    // Split the input into a chunk per thread, with the neighbouring lines as context
+    let chunks = chunks::split(input, executor.threads(), 1);
+    drop(parse);
+
+    // Errors within a chunk are reported at the line of its context, so move them down past the lines before the context
+    info_span!("solve").in_scope(|| executor.map_reduce(
+        &chunks,
+        || Ok(Sum::zero()),
+        |_, chunk| process_chunk(&chunk.lines_in(input)).map_err(|err| err.after_lines(input[..chunk.context.start].lines().count())),
+        |a, b| first_error(a, b, Total::add),
+    ))?.answer()
+}
+
+/// Sum all part numbers, i.e. numbers adjacent to a symbol, in the engine schematic, reading the file in a chunk per thread of the executor
+pub fn solve_file(path: &Path, executor: &Executor) -> io::Result<Answer> {
+    // Chunks only know their own lines, so every chunk counts its lines to move the errors of the chunks after it down past them
+    let (_, sum) = ChunkReader::open(path)?.map_reduce(
+        executor.threads(),
+        1,
+        (0, Ok(Sum::zero())),
+        |lines| (lines.own().len(), process_file_chunk(lines)),
+        |(a_lines, a), (b_lines, b)| (a_lines + b_lines, first_error(a, b.map_err(|err| err.after_lines(a_lines)), Total::add)),
+    )?;
+    sum.and_then(Total::answer)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+// Process a chunk read from a file, reporting errors at the line within the chunk instead of within its context
+fn process_file_chunk(lines: &ChunkLines) -> Result<Sum, ParseError> {
+    // Columns are compared between lines, which requires every character to be a single byte
+    if let Some((i, line)) = lines.iter().map(|line| line.text()).enumerate().find(|(_, line)| !line.is_ascii()) {
+        let offset = line.find(|c: char| !c.is_ascii()).unwrap_or_default();
+        return Err(ParseError::new(line, offset, "an ASCII character").at_line(i));
+    }
+
+    process_chunk(lines).map_err(|err| ParseError { line: err.line - lines.own().start, ..err })
+}
+
+// Process each line of a chunk using the line before and after as context, or empty space at the edges of the input
+// Lines may differ in length, so the cells past the end of the shorter lines are empty space as well
+fn process_chunk(lines: &ChunkLines) -> Result<Sum, ParseError> {
+    let schematic = Grid::padded(lines.context().iter().copied(), b'.', |c| c as u8);
+    lines.own()
+        .map(|row| process_row(&schematic, row)
+            .map_err(|column| ParseError::new(lines.context()[row], column, "a number that fits in u32").at_line(row)))
+        .try_fold(Sum::zero(), |sum, row| Ok(sum.add(row?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(4361));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(520135));
+    }
+
+    #[test]
+    fn input_file() {
+        for threads in [1, 4, 13] {
+            assert_eq!(solve_file(Path::new(aoc_core::input_path!()), &Executor::new(Strategy::Chunked, Some(threads))).unwrap(), 520135);
+        }
+    }
+
+    #[test]
+    fn ragged_lines() {
+        // Lines of varying length by trimming the trailing empty space
+        let ragged: String = EXAMPLE.lines().map(|line| line.trim_end_matches('.').to_string() + "\n").collect();
+        let expected = solve_with(&ragged, &Executor::sequential());
+        for threads in 1..=10 {
+            assert_eq!(solve_with(&ragged, &Executor::new(Strategy::Chunked, Some(threads))), expected);
+        }
+    }
+
+    #[test]
+    fn number_too_large() {
+        // The error points at the line of the number whichever chunk it ends up in
+        let input = EXAMPLE.replace(".....+.58.", ".....+.5800000000");
+        for threads in 1..=10 {
+            let err = solve_with(&input, &Executor::new(Strategy::Chunked, Some(threads))).unwrap_err();
+            assert_eq!(err.to_string(), "line 6, column 8: expected a number that fits in u32, found '5'");
+        }
+    }
+
+    #[test]
+    fn file_number_too_large() {
+        // Chunks read from a file only know their own lines, yet the error still points at the line of the number
+        let path = std::env::temp_dir().join(format!("day3-part1-{}", std::process::id()));
+        std::fs::write(&path, EXAMPLE.replace(".....+.58.", ".....+.5800000000")).unwrap();
+        for threads in 1..=10 {
+            let err = solve_file(&path, &Executor::new(Strategy::Chunked, Some(threads))).unwrap_err();
+            let err = err.get_ref().and_then(|err| err.downcast_ref::<ParseError>()).unwrap();
+            assert_eq!(err.to_string(), "line 6, column 8: expected a number that fits in u32, found '5'");
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn stacked_numbers() {
+        // The digits of numbers above or below each other are no symbols, only the 5 next to the `*` is a part number
+        assert_eq!(solve("12....\n34..*5\n"), Ok(5));
+    }
+
+    #[test]
+    fn sum_beyond_32_bits() {
+        let input = "4000000000*4000000000\n";
+        #[cfg(not(feature = "checked"))]
+        assert_eq!(solve(input), Err(ParseError::overflow("a total that fits in u32")));
+        #[cfg(feature = "checked")]
+        assert_eq!(solve(input), Ok(8_000_000_000));
+    }
+}
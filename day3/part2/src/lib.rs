@@ -0,0 +1,201 @@
+use std::io;
+use std::path::Path;
+
+use aoc_core::chunks::{self, ChunkLines, ChunkReader};
+use aoc_core::error::{first_error, ParseError};
+use aoc_core::executor::{Executor, Strategy};
+use aoc_core::grid::{Grid, Position};
+use aoc_core::int;
+use aoc_core::total::{FromValue, Total};
+
+use tracing::info_span;
+
+// The position of the first digit of the number covering a cell, together with the value of the number
+// Fails with the position of the first digit if the number does not fit, which may be on the line above or below the gear
+fn number_at(schematic: &Grid<u8>, (row, column): Position) -> Result<(Position, u32), Position> {
+    let cells = schematic.row(row);
+
+    // Grow the number to the left
+    let start = cells[..column].iter()
+        .rposition(|c| !c.is_ascii_digit())
+        .map_or(0, |before| before + 1);
+
+    // Read the number to the right
+    let (number, _) = int::number(&cells[start..]).map_err(|_| (row, start))?;
+
+    Ok(((row, start), number))
+}
+
+// Gear ratios are multiplied in 64 bits, where the product of two u32 always fits, and added up in 32 bits, or in 128 bits
+// with the `checked` feature, and checked for overflow
+#[cfg(not(feature = "checked"))]
+type Sum = aoc_core::total::Checked<u32>;
+#[cfg(feature = "checked")]
+type Sum = aoc_core::total::Checked<u128>;
+
+/// Type of the answer, which never overflows with the `checked` feature
+pub type Answer = <Sum as Total>::Answer;
+
+/// Function that processes each row of the schematic, failing with the position of a number that does not fit
+fn process_row(schematic: &Grid<u8>, row: usize) -> Result<Sum, Position> {
+    let mut result = Sum::zero();
+
+    for (column, &c) in schematic.row(row).iter().enumerate() {
+        if c == b'*' {
+            // Find the numbers adjacent to the gear, including diagonally, where a number touching it with several digits is counted once
+            let mut numbers: Vec<(Position, u32)> = schematic.neighbours8((row, column))
+                .filter(|&position| schematic[position].is_ascii_digit())
+                .map(|position| number_at(schematic, position))
+                .collect::<Result<_, _>>()?;
+            numbers.sort_unstable();
+            numbers.dedup();
+
+            // A gear touches exactly two numbers
+            if let [(_, first), (_, second)] = numbers[..] {
+                result = result.add(Sum::of(u64::from(first) * u64::from(second)));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// The executor the day runs with by default
+pub fn executor() -> Executor {
+    Executor::new(Strategy::Chunked, None)
+}
+
+/// Sum the gear ratios of all gears, i.e. `*` adjacent to exactly two numbers, in the engine schematic
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    solve_with(input, &executor())
+}
+
+/// Sum the gear ratios of all gears, i.e. `*` adjacent to exactly two numbers, in the engine schematic, processing the input in chunks of lines with the given executor
+pub fn solve_with(input: &str, executor: &Executor) -> Result<Answer, ParseError> {
+    // Columns are compared between lines, which requires every character to be a single byte
+    let parse = info_span!("parse").entered();
+    if let Some((i, line)) = input.lines().enumerate().find(|(_, line)| !line.is_ascii()) {
+        let offset = line.find(|c: char| !c.is_ascii()).unwrap_or_default();
+        return Err(ParseError::new(line, offset, "an ASCII character").at_line(i));
+    }
+
+    // Split the input into a chunk per thread, with the neighbouring lines as context
+    let chunks = chunks::split(input, executor.threads(), 1);
+    drop(parse);
+
+    // Errors within a chunk are reported at the line of its context, so move them down past the lines before the context
+    info_span!("solve").in_scope(|| executor.map_reduce(
+        &chunks,
+        || Ok(Sum::zero()),
+        |_, chunk| process_chunk(&chunk.lines_in(input)).map_err(|err| err.after_lines(input[..chunk.context.start].lines().count())),
+        |a, b| first_error(a, b, Total::add),
+    ))?.answer()
+}
+
+/// Sum the gear ratios of all gears, i.e. `*` adjacent to exactly two numbers, in the engine schematic, reading the file in a chunk per thread of the executor
+pub fn solve_file(path: &Path, executor: &Executor) -> io::Result<Answer> {
+    // Chunks only know their own lines, so every chunk counts its lines to move the errors of the chunks after it down past them
+    let (_, sum) = ChunkReader::open(path)?.map_reduce(
+        executor.threads(),
+        1,
+        (0, Ok(Sum::zero())),
+        |lines| (lines.own().len(), process_file_chunk(lines)),
+        |(a_lines, a), (b_lines, b)| (a_lines + b_lines, first_error(a, b.map_err(|err| err.after_lines(a_lines)), Total::add)),
+    )?;
+    sum.and_then(Total::answer)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+// Process a chunk read from a file, reporting errors at the line within the chunk instead of within its context
+fn process_file_chunk(lines: &ChunkLines) -> Result<Sum, ParseError> {
+    // Columns are compared between lines, which requires every character to be a single byte
+    if let Some((i, line)) = lines.iter().map(|line| line.text()).enumerate().find(|(_, line)| !line.is_ascii()) {
+        let offset = line.find(|c: char| !c.is_ascii()).unwrap_or_default();
+        return Err(ParseError::new(line, offset, "an ASCII character").at_line(i));
+    }
+
+    process_chunk(lines).map_err(|err| ParseError { line: err.line - lines.own().start, ..err })
+}
+
+// Process each line of a chunk using the line before and after as context, or empty space at the edges of the input
+// Lines may differ in length, so the cells past the end of the shorter lines are empty space as well
+fn process_chunk(lines: &ChunkLines) -> Result<Sum, ParseError> {
+    let schematic = Grid::padded(lines.context().iter().copied(), b'.', |c| c as u8);
+    lines.own()
+        .map(|row| process_row(&schematic, row)
+            .map_err(|(row, column)| ParseError::new(lines.context()[row], column, "a number that fits in u32").at_line(row)))
+        .try_fold(Sum::zero(), |sum, row| Ok(sum.add(row?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(467835));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(72514855));
+    }
+
+    #[test]
+    fn input_file() {
+        for threads in [1, 4, 13] {
+            assert_eq!(solve_file(Path::new(aoc_core::input_path!()), &Executor::new(Strategy::Chunked, Some(threads))).unwrap(), 72514855);
+        }
+    }
+
+    #[test]
+    fn ragged_lines() {
+        // Lines of varying length by trimming the trailing empty space
+        let ragged: String = EXAMPLE.lines().map(|line| line.trim_end_matches('.').to_string() + "\n").collect();
+        let expected = solve_with(&ragged, &Executor::sequential());
+        for threads in 1..=10 {
+            assert_eq!(solve_with(&ragged, &Executor::new(Strategy::Chunked, Some(threads))), expected);
+        }
+    }
+
+    #[test]
+    fn number_too_large() {
+        // The error points at the line of the number rather than the line of the gear it touches
+        let input = EXAMPLE.replace(".664.598..", ".664.59800000000");
+        for threads in 1..=10 {
+            let err = solve_with(&input, &Executor::new(Strategy::Chunked, Some(threads))).unwrap_err();
+            assert_eq!(err.to_string(), "line 10, column 6: expected a number that fits in u32, found '5'");
+        }
+
+        let path = std::env::temp_dir().join(format!("day3-part2-{}", std::process::id()));
+        std::fs::write(&path, &input).unwrap();
+        for threads in 1..=10 {
+            let err = solve_file(&path, &Executor::new(Strategy::Chunked, Some(threads))).unwrap_err();
+            let err = err.get_ref().and_then(|err| err.downcast_ref::<ParseError>()).unwrap();
+            assert_eq!(err.to_string(), "line 10, column 6: expected a number that fits in u32, found '5'");
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn ratio_beyond_32_bits() {
+        let input = "100000*100000\n";
+        #[cfg(not(feature = "checked"))]
+        assert_eq!(solve(input), Err(ParseError::overflow("a total that fits in u32")));
+        #[cfg(feature = "checked")]
+        assert_eq!(solve(input), Ok(10_000_000_000));
+    }
+}
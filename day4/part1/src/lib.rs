@@ -0,0 +1,162 @@
+use std::collections::HashSet;
+use std::io::{self, BufRead};
+
+use aoc_core::error::ParseError;
+use aoc_core::executor::{Executor, Strategy};
+use aoc_core::explain::{self, Explanation};
+use aoc_core::parse::Cursor;
+use aoc_core::solver::LineSolver;
+use aoc_core::total::{FromValue, Total};
+
+use tracing::info_span;
+
+// Count the winning numbers of a card
+fn matches(line: &str) -> Result<usize, ParseError> {
+    let mut cursor = Cursor::new(line);
+
+    // Skip the card number
+    cursor.tag("Card")?;
+    let _: u32 = cursor.number()?;
+    cursor.tag(":")?;
+
+    // Extract individual numbers left of the pipe into an hashset
+    let mut left_numbers = HashSet::new();
+    while cursor.peek().is_some_and(|c| c != '|') {
+        left_numbers.insert(cursor.number::<u32>()?);
+    }
+    cursor.tag("|")?;
+
+    // Count the number of matches on the right by filtering out number that are not on the left and counting the sum.
+    Ok(cursor.numbers::<u32>()?
+        .iter()
+        .filter(|num| left_numbers.contains(num))
+        .count())
+}
+
+// The score of a card, which doubles with every winning number after the first, or `None` if it does not fit
+#[cfg(not(feature = "checked"))]
+fn score(count: usize) -> Option<Answer> {
+    match count {
+        0 => Some(0),
+        _ => u32::try_from(count - 1).ok().and_then(|shift| Answer::from(1_u8).checked_shl(shift)),
+    }
+}
+
+// The score of a card, which doubles with every winning number after the first
+#[cfg(feature = "checked")]
+fn score(count: usize) -> Option<Answer> {
+    match count {
+        0 => Some(Answer::from(0_u8)),
+        _ => Some(Answer::from(1_u8) << (count - 1)),
+    }
+}
+
+/// Function that processes each line
+pub fn process_line(line: &str) -> Result<Answer, ParseError> {
+    matches(line).and_then(|count| score(count).ok_or_else(|| ParseError::overflow("a score that fits in u32")))
+}
+
+/// The number of winning numbers and the score of every card in the input
+pub fn explain(input: &str) -> Result<Vec<Explanation>, ParseError> {
+    explain::lines(input, |line| {
+        let count = matches(line)?;
+        Ok(Explanation::new().number("matches", count).number("score", process_line(line)?))
+    })
+}
+
+// Scores double with every winning number, so they are summed in 32 bits and checked for overflow, or as big integers
+// with the `checked` feature
+#[cfg(not(feature = "checked"))]
+type Sum = aoc_core::total::Checked<u32>;
+#[cfg(feature = "checked")]
+type Sum = num::BigUint;
+
+/// Type of the answer, which never overflows with the `checked` feature
+pub type Answer = <Sum as Total>::Answer;
+
+/// Sums the scores of all scratchcards
+pub struct Scratchcards;
+
+impl LineSolver for Scratchcards {
+    type Accumulator = Sum;
+    type Answer = Answer;
+
+    fn identity(&self) -> Sum {
+        Sum::zero()
+    }
+
+    fn map(&self, _: usize, line: &str) -> Result<Sum, ParseError> {
+        process_line(line).map(Sum::of)
+    }
+
+    fn combine(&self, a: Sum, b: Sum) -> Sum {
+        a.add(b)
+    }
+
+    fn finish(&self, sum: Sum) -> Result<Answer, ParseError> {
+        sum.answer()
+    }
+}
+
+/// The executor the day runs with by default
+pub fn executor() -> Executor {
+    Executor::new(Strategy::Shared, None)
+}
+
+/// Sum the scores of all scratchcards
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    solve_with(input, &executor())
+}
+
+/// Sum the scores of all scratchcards, processing the lines with the given executor
+pub fn solve_with(input: &str, executor: &Executor) -> Result<Answer, ParseError> {
+    // Process the lines and sum the scores
+    info_span!("solve").in_scope(|| executor.solve(&Scratchcards, input))
+}
+
+/// Sum the scores of all scratchcards, processing the lines with the given executor as they are read from the reader
+pub fn solve_reader(reader: impl BufRead + Send, executor: &Executor) -> io::Result<Answer> {
+    info_span!("solve").in_scope(|| executor.solve_reader(&Scratchcards, reader))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(Answer::from(13_u32)));
+    }
+
+    #[test]
+    fn explain_example() {
+        let explanations = explain(EXAMPLE).unwrap();
+        assert_eq!(explanations[0], Explanation::new().number("matches", 4).number("score", 8));
+        assert_eq!(explanations[4], Explanation::new().number("matches", 0).number("score", 0));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(Answer::from(24175_u32)));
+    }
+
+    #[test]
+    fn score_beyond_32_bits() {
+        // Forty winning numbers score 2^39
+        let numbers: Vec<String> = (1..=40).map(|number| number.to_string()).collect();
+        let input = format!("Card 1: {} | {}\n", numbers.join(" "), numbers.join(" "));
+        #[cfg(not(feature = "checked"))]
+        assert_eq!(solve(&input).unwrap_err().to_string(), "the answer overflowed, expected a score that fits in u32");
+        #[cfg(feature = "checked")]
+        assert_eq!(solve(&input), Ok(Answer::from(1_u64 << 39)));
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::HashSet;
+use std::io::{self, BufRead};
+
+use aoc_core::error::ParseError;
+use aoc_core::executor::{Executor, Strategy};
+use aoc_core::explain::Explanation;
+use aoc_core::parse::Cursor;
+use aoc_core::solver::LineSolver;
+
+use num::CheckedAdd;
+use tracing::info_span;
+
+// Function that processes each line to its card number and number of winning numbers
+fn process_line(line: &str) -> Result<(usize, usize), ParseError> {
+    let mut cursor = Cursor::new(line);
+
+    // Get the card number
+    cursor.tag("Card")?;
+    let card_number = cursor.number()?;
+    cursor.tag(":")?;
+
+    // Extract individual numbers left of the pipe into an hashset
+    let mut left_numbers = HashSet::new();
+    while cursor.peek().is_some_and(|c| c != '|') {
+        left_numbers.insert(cursor.number::<u32>()?);
+    }
+    cursor.tag("|")?;
+
+    // Count the number of matches on the right by filtering out number that are not on the left and counting the sum.
+    let count = cursor.numbers::<u32>()?
+        .iter()
+        .filter(|num| left_numbers.contains(num))
+        .count();
+
+    Ok((card_number, count))
+}
+
+/// Number of cards, which can double with every card and is checked for overflow, or is a big integer with the `checked` feature
+#[cfg(not(feature = "checked"))]
+pub type Count = usize;
+#[cfg(feature = "checked")]
+pub type Count = num::BigUint;
+
+/// A scratchcard with the index of its line, its card number and its number of winning numbers
+pub struct Card {
+    index: usize,
+    number: usize,
+    wins: usize,
+    // The line of a card that is not numbered by its position, to point at if its number is out of range or repeated
+    line: Option<String>,
+}
+
+/// Counts the total number of scratchcards we end up with after winning copies of subsequent cards
+pub struct Scratchcards;
+
+impl LineSolver for Scratchcards {
+    type Accumulator = Vec<Card>;
+    type Answer = Count;
+
+    fn identity(&self) -> Vec<Card> {
+        Vec::new()
+    }
+
+    fn map(&self, index: usize, line: &str) -> Result<Vec<Card>, ParseError> {
+        Ok(vec![Card::parse(index, line)?])
+    }
+
+    fn combine(&self, mut a: Vec<Card>, b: Vec<Card>) -> Vec<Card> {
+        a.extend(b);
+        a
+    }
+
+    fn finish(&self, mut cards: Vec<Card>) -> Result<Count, ParseError> {
+        // Lines are combined in any order, so put the cards back in the order of the lines
+        cards.sort_unstable_by_key(|card| card.index);
+
+        // Summing the number of copies of all cards gets us our number of cards.
+        copies(&card_wins(&cards)?)?
+            .iter()
+            .try_fold(Count::from(0_u8), |sum, copies| CheckedAdd::checked_add(&sum, copies))
+            .ok_or_else(overflow)
+    }
+}
+
+impl Card {
+    // Parse the card on the line with the given index
+    fn parse(index: usize, line: &str) -> Result<Card, ParseError> {
+        let (number, wins) = process_line(line)?;
+        let line = (number != index + 1).then(|| line.to_string());
+        Ok(Card { index, number, wins, line })
+    }
+
+    // An error pointing at the card number of the line of a card that is not numbered by its position
+    fn error(&self, expected: impl Into<String>) -> ParseError {
+        let line = self.line.as_deref().unwrap_or_default();
+        let offset = line.find(|c: char| c.is_ascii_digit()).unwrap_or_default();
+        ParseError::new(line, offset, expected).at_line(self.index)
+    }
+}
+
+// Put the number of winning numbers of the cards, which are in the order of their lines, in the order of their card numbers
+fn card_wins(cards: &[Card]) -> Result<Vec<usize>, ParseError> {
+    // Create an array to store the card with each card number, if a card filled it yet
+    let mut final_cards: Vec<Option<&Card>> = vec![None; cards.len()];
+    for card in cards {
+        // A card number out of range is never the position of the card
+        let Some(slot) = card.number.checked_sub(1).and_then(|index| final_cards.get_mut(index)) else {
+            return Err(card.error(format!("a card number from 1 to {}", cards.len())));
+        };
+        // Of two cards with the same number at most one is at its position, so point at the later one that is not
+        if let Some(earlier) = slot.replace(card) {
+            let repeated = if card.line.is_some() { card } else { earlier };
+            return Err(repeated.error("a card number that is not repeated"));
+        }
+    }
+    // Every card number is in range and none is repeated, so every card filled its own slot
+    Ok(final_cards.into_iter().flatten().map(|card| card.wins).collect())
+}
+
+// Count the number of copies we end up with of each card, failing if they overflow
+fn copies(card_wins: &[usize]) -> Result<Vec<Count>, ParseError> {
+    // Start from the front, where every copy of a card wins a copy of each of the cards after it
+    let mut copies = vec![Count::from(1_u8); card_wins.len()];
+    for (i, &wins) in card_wins.iter().enumerate() {
+        let (won, next) = copies.split_at_mut(i + 1);
+        for card in next.iter_mut().take(wins) {
+            *card = CheckedAdd::checked_add(card, &won[i]).ok_or_else(overflow)?;
+        }
+    }
+    Ok(copies)
+}
+
+// The error for a number of cards that overflows
+fn overflow() -> ParseError {
+    ParseError::overflow("a number of cards that fits in usize")
+}
+
+/// The card number, the number of winning numbers and the number of copies we end up with of every card in the input
+pub fn explain(input: &str) -> Result<Vec<Explanation>, ParseError> {
+    let cards = input.lines()
+        .enumerate()
+        .map(|(index, line)| Card::parse(index, line).map_err(|err| err.at_line(index)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let copies = copies(&card_wins(&cards)?)?;
+    Ok(cards.iter()
+        .map(|card| Explanation::new().number("card", card.number).number("matches", card.wins).number("copies", copies[card.number - 1].to_string()))
+        .collect())
+}
+
+/// The executor the day runs with by default
+pub fn executor() -> Executor {
+    Executor::new(Strategy::Shared, None)
+}
+
+/// Count the total number of scratchcards we end up with after winning copies of subsequent cards
+pub fn solve(input: &str) -> Result<Count, ParseError> {
+    solve_with(input, &executor())
+}
+
+/// Count the total number of scratchcards we end up with, processing the lines with the given executor
+pub fn solve_with(input: &str, executor: &Executor) -> Result<Count, ParseError> {
+    // Parse the number of winning numbers of each card and count the cards we win
+    info_span!("solve").in_scope(|| executor.solve(&Scratchcards, input))
+}
+
+/// Count the total number of scratchcards we end up with, processing the lines with the given executor as they are read from the reader
+pub fn solve_reader(reader: impl BufRead + Send, executor: &Executor) -> io::Result<Count> {
+    info_span!("solve").in_scope(|| executor.solve_reader(&Scratchcards, reader))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(Count::from(30_usize)));
+    }
+
+    #[test]
+    fn explain_example() {
+        let explanations = explain(EXAMPLE).unwrap();
+        assert_eq!(explanations[0], Explanation::new().number("card", 1).number("matches", 4).number("copies", 1));
+        assert_eq!(explanations[3], Explanation::new().number("card", 4).number("matches", 1).number("copies", 8));
+        assert_eq!(explain(&EXAMPLE.replace("Card 4", "Card 9")).unwrap_err().line, 4);
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(Count::from(18846301_usize)));
+    }
+
+    #[test]
+    fn counts_beyond_64_bits() {
+        // Every card wins a copy of all cards after it, so the number of cards doubles with every card
+        let input: String = (1..=80)
+            .map(|card| {
+                let numbers: Vec<String> = (1..=80 - card).map(|number| number.to_string()).collect();
+                format!("Card {}: {} | {}\n", card, numbers.join(" "), numbers.join(" "))
+            })
+            .collect();
+        #[cfg(not(feature = "checked"))]
+        assert_eq!(solve(&input), Err(overflow()));
+        #[cfg(feature = "checked")]
+        assert_eq!(solve(&input), Ok((Count::from(1_u8) << 80_usize) - 1_u8));
+    }
+
+    #[test]
+    fn card_number_out_of_range() {
+        let input = EXAMPLE.replace("Card 4", "Card 9");
+        for strategy in Strategy::ALL {
+            let err = solve_with(&input, &Executor::new(strategy, Some(3))).unwrap_err();
+            assert_eq!(err.to_string(), "line 4, column 6: expected a card number from 1 to 6, found '9'");
+        }
+    }
+
+    #[test]
+    fn repeated_card_number() {
+        let input = EXAMPLE.replace("Card 4", "Card 2");
+        for strategy in Strategy::ALL {
+            let err = solve_with(&input, &Executor::new(strategy, Some(3))).unwrap_err();
+            assert_eq!(err.to_string(), "line 4, column 6: expected a card number that is not repeated, found '2'");
+        }
+        // The repeated card is the one that is not numbered by its position
+        let err = solve("Card 2: 1 | 1\nCard 2: 1 | 1\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 6: expected a card number that is not repeated, found '2'");
+    }
+}
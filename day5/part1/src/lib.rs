@@ -0,0 +1,183 @@
+use aoc_core::error::ParseError;
+use aoc_core::parse::Cursor;
+
+use tracing::info_span;
+
+// Numbers are widened to 128 bits with the `checked` feature, where the end of a range and a mapped number always fit
+// since every number of the input fits in 64 bits
+#[cfg(not(feature = "checked"))]
+type Number = u64;
+#[cfg(feature = "checked")]
+type Number = u128;
+
+// Parse the numbers of the seeds line
+fn parse_seeds(line: &str) -> Result<Vec<Number>, ParseError> {
+    let mut cursor = Cursor::new(line);
+    cursor.tag("seeds:")?;
+    let seeds: Vec<Number> = cursor.numbers::<u64>()?
+        .into_iter()
+        .map(|seed| seed as Number)
+        .collect();
+
+    if seeds.is_empty() {
+        return Err(cursor.error("a seed"));
+    }
+    Ok(seeds)
+}
+
+// Check that a line names a map, like `seed-to-soil map:`
+fn parse_map_name(line: &str) -> Result<(), ParseError> {
+    let mut cursor = Cursor::new(line);
+    if cursor.word().is_none() {
+        return Err(cursor.error("the name of a map"));
+    }
+    cursor.tag("map:")?;
+    cursor.end()
+}
+
+// Parse a line of a map to its destination start, source start and length,
+// failing if the destination range does not end within the numbers
+fn parse_transfer_function(line: &str) -> Result<[Number; 3], ParseError> {
+    let mut cursor = Cursor::new(line);
+    let destination = cursor.number::<u64>()? as Number;
+    let source = cursor.number::<u64>()? as Number;
+    cursor.skip_whitespace();
+    let length_offset = cursor.offset();
+    let length = cursor.number::<u64>()? as Number;
+    cursor.end()?;
+
+    // Every number of the source range maps to a number of the destination range, so its end has to fit
+    if length > 0 && destination.checked_add(length - 1).is_none() {
+        return Err(cursor.error_at(length_offset, "a length that keeps the destination range within 64 bits"));
+    }
+    Ok([destination, source, length])
+}
+
+/// Find the lowest location number that corresponds to any of the initial seeds
+pub fn solve(input: &str) -> Result<Number, ParseError> {
+    // Create a single parser for all the lines, numbered to report errors
+    let mut parser = input.lines().enumerate();
+
+    // Read seeds
+    let (i, line) = parser.next().ok_or_else(|| ParseError::end_of_input(0, "a line with seeds"))?;
+    let mut seeds = info_span!("parse").in_scope(|| parse_seeds(line).map_err(|err| err.at_line(i)))?;
+
+    // While there are lines to be parsed, read the maps separated by empty lines
+    while let Some((i, line)) = parser.next() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        // Get the transfer functions of a map by parsing every line after its name up to the next empty line
+        let transfer_functions = info_span!("parse").in_scope(|| {
+            parse_map_name(line).map_err(|err| err.at_line(i))?;
+            parser.by_ref()
+                .take_while(|(_, line)| !line.trim().is_empty())
+                .map(|(i, line)| parse_transfer_function(line).map_err(|err| err.at_line(i)))
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+        let _solve = info_span!("solve").entered();
+
+        // Use an array to denote whether we already used a transfer function on a value
+        let mut transfered = vec![0; seeds.len()];
+
+        // Use the transfer functions on the values
+        for transfer_function in transfer_functions  {
+            for i in 0..seeds.len() {
+                // If we haven't transfered this value yet and it is within range of the transfer function
+                // Compare the distance from the start of the source range, since the end of the range may not fit
+                if transfered[i] == 0 && seeds[i] >= transfer_function[1] && seeds[i] - transfer_function[1] < transfer_function[2] {
+                    // Transfer the value using the transfer function
+                    seeds[i] = transfer_function[0] + (seeds[i] - transfer_function[1]);
+
+                    // Mark it as transfered so we don't transfer a value twice
+                    transfered[i] = 1;
+                }
+            }
+        }
+    }
+
+    // Get the lowest location number, there is at least one seed
+    Ok(*seeds.iter().min().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(35));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(484023871));
+    }
+
+    #[test]
+    fn lines_of_a_map() {
+        let err = solve("seeds: 79\n\nseed-to-soil map:\n50 98 2\nabc\n52 50 48\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 5, column 1: expected a number, found 'a'");
+
+        let err = solve("seeds: 79\n\nseed-to-soil map:\n50 98 2 x\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 4, column 9: expected the end of the line, found 'x'");
+
+        let err = solve("seeds: 79\n\nseed-to-soil map:\n50 98 2\n\n52 50 48\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 6, column 4: expected `map:`, found '5'");
+    }
+
+    #[test]
+    fn ranges_beyond_64_bits() {
+        // The end of the source range of the map does not fit in 64 bits
+        assert_eq!(solve("seeds: 18446744073709551615\n\nseed-to-soil map:\n0 18446744073709551610 10\n"), Ok(5));
+    }
+
+    #[cfg(not(feature = "checked"))]
+    #[test]
+    fn destination_beyond_64_bits() {
+        let err = solve("seeds: 5\n\nseed-to-soil map:\n18446744073709551610 0 10\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 4, column 24: expected a length that keeps the destination range within 64 bits, found '1'");
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn destination_beyond_64_bits() {
+        assert_eq!(solve("seeds: 5\n\nseed-to-soil map:\n18446744073709551610 0 10\n"), Ok(18446744073709551615));
+    }
+}
@@ -0,0 +1,314 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use aoc_core::error::ParseError;
+use aoc_core::parse::Cursor;
+
+use tracing::info_span;
+
+// Numbers are widened to 128 bits with the `checked` feature, where the ends of ranges and mapped numbers always fit
+// since every number of the input fits in 64 bits
+#[cfg(not(feature = "checked"))]
+type Number = u64;
+#[cfg(feature = "checked")]
+type Number = u128;
+
+// Read a number of the input, which is never negative and fits in 64 bits
+fn number(cursor: &mut Cursor) -> Result<Number, ParseError> {
+    Ok(cursor.number::<u64>()? as Number)
+}
+
+// A range of values as (lowest_output, type, (start_range, end_range)), ordered by the lowest output they map to
+type OutputRange = (Number, usize, (Number, Number));
+
+// Parse the seeds line to its seed ranges as (start, end), leaving out the ranges of length zero since they hold no seeds
+fn parse_seed_ranges(line: &str) -> Result<Vec<(Number, Number)>, ParseError> {
+    let mut cursor = Cursor::new(line);
+    cursor.tag("seeds:")?;
+    if cursor.at_end() {
+        return Err(cursor.error("a seed"));
+    }
+
+    let mut seeds = Vec::new();
+    while !cursor.at_end() {
+        let start = number(&mut cursor)?;
+        if cursor.at_end() {
+            return Err(cursor.error("the length of the last seed range"));
+        }
+        let length_offset = cursor.offset();
+        let length = number(&mut cursor)?;
+        if length > 0 {
+            let end = start.checked_add(length - 1)
+                .ok_or_else(|| cursor.error_at(length_offset, "a length that keeps the seed range within 64 bits"))?;
+            seeds.push((start, end));
+        }
+    }
+    Ok(seeds)
+}
+
+// Check that a line names a map, like `seed-to-soil map:`
+fn parse_map_name(line: &str) -> Result<(), ParseError> {
+    let mut cursor = Cursor::new(line);
+    if cursor.word().is_none() {
+        return Err(cursor.error("the name of a map"));
+    }
+    cursor.tag("map:")?;
+    cursor.end()
+}
+
+// Parse a line of a map to its (dest_start, dest_end, source_start), or `None` if its ranges are empty, failing like part 1
+// if the destination range does not end within the numbers. Numbers beyond the end of the numbers are no seeds,
+// so a source range that does not end within the numbers is cut short there together with its destination range
+fn parse_transfer_function(line: &str) -> Result<Option<(Number, Number, Number)>, ParseError> {
+    let mut cursor = Cursor::new(line);
+    let destination = number(&mut cursor)?;
+    let source = number(&mut cursor)?;
+    cursor.skip_whitespace();
+    let length_offset = cursor.offset();
+    let length = number(&mut cursor)?;
+    cursor.end()?;
+
+    if length == 0 {
+        return Ok(None);
+    }
+    // Every number of the source range maps to a number of the destination range, so its end has to fit
+    let destination_end = destination.checked_add(length - 1)
+        .ok_or_else(|| cursor.error_at(length_offset, "a length that keeps the destination range within 64 bits"))?;
+    let source_length = source.checked_add(length - 1).map_or_else(|| Number::MAX - source + 1, |_| length);
+    Ok(Some((destination, destination_end - (length - source_length), source)))
+}
+
+/// Find the lowest location number that corresponds to any seed in the initial seed ranges
+///
+/// Seed ranges of length zero hold no seeds, so they are left out rather than reported.
+pub fn solve(input: &str) -> Result<Number, ParseError> {
+    // Create a single parser for all the lines, numbered to report errors
+    let parse = info_span!("parse").entered();
+    let mut parser = input.lines().enumerate();
+
+    // Read the seed ranges as (start, end)
+    let (i, seeds_line) = parser.next().ok_or_else(|| ParseError::end_of_input(0, "a line with seeds"))?;
+    let seeds = parse_seed_ranges(seeds_line).map_err(|err| err.at_line(i))?;
+
+    // Initialize the vector to store grouped transfer functions
+    // groups of transfer functions containing (dest_start, dest_end, source_start)
+    let mut transfer_functions_grouped: Vec<Vec<(Number, Number, Number)>> = Vec::new();
+
+    // While there are lines to be parsed, read the maps separated by empty lines
+    while let Some((i, line)) = parser.next() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        // Get the transfer functions of a map by parsing every line after its name up to the next empty line
+        // and add it to the list of transfer functions groups
+        parse_map_name(line).map_err(|err| err.at_line(i))?;
+        transfer_functions_grouped.push(parser.by_ref()
+            .take_while(|(_, line)| !line.trim().is_empty())
+            // Map a single line to a tuple (dest_start, dest_end, source_start)
+            .map(|(i, line)| parse_transfer_function(line).map_err(|err| err.at_line(i)))
+            .filter_map(Result::transpose)
+            .collect::<Result<_, ParseError>>()?);
+    }
+
+    // Sort the source ranges of every group as (source_start, source_end), to find the numbers no transfer function maps
+    let sources_grouped: Vec<Vec<(Number, Number)>> = transfer_functions_grouped.iter()
+        .map(|transfer_functions| {
+            let mut sources: Vec<(Number, Number)> = transfer_functions.iter()
+                .map(|(dest_start, dest_end, source_start)| (*source_start, source_start + (dest_end - dest_start)))
+                .collect();
+            sources.sort_unstable();
+            sources
+        })
+        .collect();
+
+    drop(parse);
+    let _solve = info_span!("solve").entered();
+
+    // Without maps the seeds are their own locations, like in part 1
+    if transfer_functions_grouped.is_empty() {
+        return seeds.iter()
+            .map(|&(start, _)| start)
+            .min()
+            .ok_or_else(|| ParseError::new(seeds_line, 0, "a seed range with a location").at_line(0));
+    }
+
+    // We traverse backward from the lowest outputs first to find the best input value that is a seed.
+
+    // Create a queue of value ranges that always pops the range with the lowest output first
+    // Ranges of type 0 are ranges of seeds we know exist
+    let mut possible_output_queue: BinaryHeap<Reverse<OutputRange>> = BinaryHeap::new();
+
+    // Start from all possible locations
+    possible_output_queue.push(Reverse((0, transfer_functions_grouped.len(), (0, Number::MAX))));
+
+    // Every value in a range maps to the output of the start of the range plus its distance to the start,
+    // so the lowest output of a range is never lower than the lowest output of the range it came from.
+    // This makes the first range of existing seeds we pop hold the lowest location number.
+    // The output of a value is added to its distance to the start of its range, which never exceeds the locations,
+    // and the values that map to a range are within the source range of the map, whose end was checked to fit.
+    while let Some(Reverse((lowest_output, output_group, (output_start, output_end)))) = possible_output_queue.pop() {
+        if output_group == 0 {
+            return Ok(lowest_output);
+        }
+
+        // Find the ranges of the lower type that the transfer functions of the group map into the current range
+        let transfer_group = output_group - 1;
+        let mut transfers: Vec<(Number, Number, Number)> = transfer_functions_grouped[transfer_group].iter()
+            .filter(|(dest_start, dest_end, _)| *dest_start <= output_end && output_start <= *dest_end)
+            .map(|(dest_start, dest_end, source_start)| {
+                let start = output_start.max(*dest_start);
+                let end = output_end.min(*dest_end);
+                (lowest_output + (start - output_start), source_start + (start - dest_start), source_start + (end - dest_start))
+            })
+            .collect();
+
+        // Values that no transfer function maps from map to themselves, so add the parts of the range outside all sources
+        // The start of the values that are not mapped is `None` once a source range ends at the end of the numbers
+        let mut direct_start = Some(output_start);
+        for &(source_start, source_end) in &sources_grouped[transfer_group] {
+            if let Some(start) = direct_start.filter(|&start| source_start > start && start <= output_end) {
+                transfers.push((lowest_output + (start - output_start), start, output_end.min(source_start - 1)));
+            }
+            direct_start = direct_start.and_then(|start| if source_end < start { Some(start) } else { source_end.checked_add(1) });
+        }
+        if let Some(start) = direct_start.filter(|&start| start <= output_end) {
+            transfers.push((lowest_output + (start - output_start), start, output_end));
+        }
+
+        for (lowest_output, start, end) in transfers {
+            if transfer_group == 0 {
+                // Only keep the parts of the ranges of seeds that exist
+                for &(seed_start, seed_end) in &seeds {
+                    if seed_start <= end && start <= seed_end {
+                        let seed = start.max(seed_start);
+                        possible_output_queue.push(Reverse((lowest_output + (seed - start), 0, (seed, end.min(seed_end)))));
+                    }
+                }
+            } else {
+                possible_output_queue.push(Reverse((lowest_output, transfer_group, (start, end))));
+            }
+        }
+    }
+
+    // All seed ranges are empty or outside of the numbers that lead to a location
+    Err(ParseError::new(seeds_line, 0, "a seed range with a location").at_line(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(46));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(46294175));
+    }
+
+    #[test]
+    fn empty_seed_range() {
+        assert_eq!(solve("seeds: 79 0\n\nseed-to-soil map:\n50 98 2\n"), Err(ParseError::new("seeds: 79 0", 0, "a seed range with a location").at_line(0)));
+    }
+
+    #[test]
+    fn negative_numbers() {
+        let err = solve("seeds: 79 -14 -3 5\n\nseed-to-soil map:\n0 0 1\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 11: expected a number, found '-'");
+
+        let err = solve("seeds: 79 14\n\nseed-to-soil map:\n0 -1 1\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 4, column 3: expected a number, found '-'");
+    }
+
+    #[test]
+    fn lines_of_a_map() {
+        let err = solve("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\nabc\n52 50 48\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 5, column 1: expected a number, found 'a'");
+
+        let err = solve("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n\n52 50 48\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 6, column 4: expected `map:`, found '5'");
+    }
+
+    #[test]
+    fn large_locations() {
+        // Locations beyond half of the numbers are found as well, like part 1 does
+        assert_eq!(solve("seeds: 5000000000000000000 1\n\nseed-to-soil map:\n0 0 1\n"), Ok(5000000000000000000));
+        assert_eq!(solve("seeds: 0 2\n\nseed-to-soil map:\n9223372036854775806 0 2\n"), Ok(9223372036854775806));
+        assert_eq!(solve("seeds: 9223372036854775806 1\n\nseed-to-soil map:\n0 9223372036854775806 1\n"), Ok(0));
+    }
+
+    #[test]
+    fn empty_transfer_function() {
+        assert_eq!(solve("seeds: 5 3\n\nseed-to-soil map:\n0 6 0\n"), Ok(5));
+    }
+
+    #[test]
+    fn no_maps() {
+        // The seeds are their own locations, like in part 1
+        assert_eq!(solve("seeds: 79 14 55 13\n"), Ok(55));
+        assert_eq!(solve("seeds: 79 0\n"), Err(ParseError::new("seeds: 79 0", 0, "a seed range with a location").at_line(0)));
+    }
+
+    #[test]
+    fn numbers_beyond_63_bits() {
+        // Every number of part 1 is accepted, and source ranges that end beyond the numbers hold no seeds there
+        assert_eq!(solve("seeds: 9223372036854775808 1\n\nseed-to-soil map:\n0 0 1\n"), Ok(9223372036854775808));
+        assert_eq!(solve("seeds: 18446744073709551615 1\n\nseed-to-soil map:\n0 18446744073709551610 10\n"), Ok(5));
+        assert_eq!(solve("seeds: 18446744073709551614 2\n\nseed-to-soil map:\n7 18446744073709551615 10\n"), Ok(7));
+    }
+
+    #[cfg(not(feature = "checked"))]
+    #[test]
+    fn ranges_beyond_64_bits() {
+        let err = solve("seeds: 18446744073709551615 2\n\nseed-to-soil map:\n0 0 1\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 29: expected a length that keeps the seed range within 64 bits, found '2'");
+
+        let err = solve("seeds: 0 1\n\nseed-to-soil map:\n18446744073709551610 0 10\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 4, column 24: expected a length that keeps the destination range within 64 bits, found '1'");
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn ranges_beyond_64_bits() {
+        assert_eq!(solve("seeds: 18446744073709551615 2\n\nseed-to-soil map:\n0 0 1\n"), Ok(18446744073709551615));
+        assert_eq!(solve("seeds: 0 1\n\nseed-to-soil map:\n18446744073709551610 0 10\n"), Ok(18446744073709551610));
+    }
+}
@@ -0,0 +1,65 @@
+//! Code shared by both parts of day 6 of Advent of Code 2023.
+
+/// Count the integer solutions for n to the problem d + 1 <= n * (t - n), i.e. the ways to beat a record distance d in a race of time t
+pub fn find_integer_solution_range(t: u64, d: u64) -> u64 {
+    // Calculate the discriminant of n^2 - t * n + d + 1, in 128 bits since the square of the time does not fit in 64
+    let (t, d) = (u128::from(t), u128::from(d));
+
+    // If there are no valid solutions to the problem we return 0
+    let Some(discriminant) = (t * t).checked_sub(4 * (d + 1)) else {
+        return 0
+    };
+
+    // Calculate the lowest and highest valid integer solutions
+    // The integer square root rounds down, which gives the same integer solutions as the exact square root
+    let discriminant_sqrt = discriminant.isqrt();
+
+    let n1 = (t - discriminant_sqrt).div_ceil(2);
+    let n2 = (t + discriminant_sqrt) / 2;
+
+    // Return the range between the highest and lowest integer solutions, which is empty if they are in between two integers
+    (n2 + 1 - n1) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // Distance travelled when holding the button for n of the t milliseconds
+    fn distance(t: u64, n: u64) -> u128 {
+        u128::from(n) * u128::from(t - n)
+    }
+
+    // Times and records around the best distance of the race, where the discriminant is close to a square
+    fn close_races() -> impl Strategy<Value = (u64, u64)> {
+        (any::<u64>(), -3_i128..=3).prop_map(|(t, offset)| {
+            let best = distance(t, t / 2) as i128;
+            (t, (best + offset).clamp(0, u64::MAX as i128) as u64)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn counts_every_solution(t in 0_u64..2000, d in 0_u64..1_000_100) {
+            let count = (0..=t).filter(|&n| distance(t, n) > u128::from(d)).count();
+            prop_assert_eq!(find_integer_solution_range(t, d), count as u64);
+        }
+
+        #[test]
+        fn solutions_are_tight((t, d) in prop_oneof![(any::<u64>(), any::<u64>()), close_races()]) {
+            let count = find_integer_solution_range(t, d);
+            if count == 0 {
+                // Not even holding the button for half the race beats the record
+                prop_assert!(distance(t, t / 2) <= u128::from(d));
+            } else {
+                // The solutions are symmetric around half the race, the shortest beats the record and one shorter does not
+                prop_assert!(count <= t + 1 && (t + 1 - count).is_multiple_of(2));
+                let shortest = (t + 1 - count) / 2;
+                prop_assert!(distance(t, shortest) > u128::from(d));
+                prop_assert!(shortest == 0 || distance(t, shortest - 1) <= u128::from(d));
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use aoc_core::error::ParseError;
+use aoc_core::parse::Cursor;
+use day6_common::find_integer_solution_range;
+
+use num::CheckedMul;
+use tracing::info_span;
+
+// Parse the numbers following the descriptor on a line
+fn parse_numbers(index: usize, line: Option<&str>, descriptor: &str) -> Result<Vec<u64>, ParseError> {
+    let line = line.ok_or_else(|| ParseError::end_of_input(index, format!("`{}`", descriptor)))?;
+    let mut cursor = Cursor::new(line);
+    cursor.tag(descriptor)
+        .and_then(|_| cursor.numbers())
+        .map_err(|err| err.at_line(index))
+}
+
+/// Product of the numbers of ways to beat the records, which is checked for overflow, or is a big integer with the `checked` feature
+#[cfg(not(feature = "checked"))]
+pub type Answer = u64;
+#[cfg(feature = "checked")]
+pub type Answer = num::BigUint;
+
+/// Multiply the number of ways to beat the record of every race
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    // Create a single parser for all the lines
+    let parse = info_span!("parse").entered();
+    let mut parser = input.lines();
+
+    // Read times and distances, skipping the descriptors
+    let times = parse_numbers(0, parser.next(), "Time:")?;
+    let distances = parse_numbers(1, parser.next(), "Distance:")?;
+    if times.len() != distances.len() {
+        let line = input.lines().nth(1).unwrap_or_default();
+        return Err(ParseError::new(line, line.len(), format!("{} distances, one for every race", times.len())).at_line(1));
+    }
+
+    drop(parse);
+
+    // Zip them together
+    // Then find their respective range of integer solutions
+    // Then multiply those together, where a race without a way to win makes the product zero however large the others are
+    let _solve = info_span!("solve").entered();
+    let ways: Vec<u64> = times.into_iter()
+        .zip(distances)
+        .map(|(time, distance)| find_integer_solution_range(time, distance))
+        .collect();
+    if ways.contains(&0) {
+        return Ok(Answer::from(0_u8));
+    }
+    ways.into_iter()
+        .try_fold(Answer::from(1_u8), |product, ways| CheckedMul::checked_mul(&product, &Answer::from(ways)))
+        .ok_or_else(|| ParseError::overflow("a product that fits in u64"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(Answer::from(288_u64)));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(Answer::from(1624896_u64)));
+    }
+
+    #[test]
+    fn product_beyond_64_bits() {
+        // Holding the button for all but the first and last millisecond beats the records, in each of the races
+        let input = "Time: 18446744073709551615 18446744073709551615\nDistance: 0 0\n";
+        #[cfg(not(feature = "checked"))]
+        assert_eq!(solve(input), Err(ParseError::overflow("a product that fits in u64")));
+        #[cfg(feature = "checked")]
+        assert_eq!(solve(input), Ok(Answer::from(u64::MAX - 1) * Answer::from(u64::MAX - 1)));
+        // A race that cannot be won makes the product zero
+        assert_eq!(solve("Time: 18446744073709551615 18446744073709551615 1\nDistance: 0 0 0\n"), Ok(Answer::from(0_u8)));
+    }
+}
@@ -0,0 +1,65 @@
+use aoc_core::error::ParseError;
+use aoc_core::int::{self, IntError};
+use aoc_core::parse::Cursor;
+use day6_common::find_integer_solution_range;
+
+use tracing::info_span;
+
+// Parse the digits following the descriptor on a line as a single number, ignoring the spaces between them
+fn parse_number(index: usize, line: Option<&str>, descriptor: &str) -> Result<u64, ParseError> {
+    let line = line.ok_or_else(|| ParseError::end_of_input(index, format!("`{}`", descriptor)))?;
+    let mut cursor = Cursor::new(line);
+    cursor.tag(descriptor).map_err(|err| err.at_line(index))?;
+
+    // Only digits and spaces may follow the descriptor
+    if let Some(offset) = cursor.rest().find(|c: char| !c.is_ascii_digit() && !c.is_whitespace()) {
+        return Err(cursor.error_at(cursor.offset() + offset, "a digit").at_line(index));
+    }
+
+    // Read all digits together as a single number
+    int::concat_digits(cursor.rest().as_bytes()).map_err(|err| match err {
+        IntError::Missing => cursor.error("a number"),
+        IntError::Overflow => cursor.error("a number that fits in u64"),
+    }.at_line(index))
+}
+
+/// Find the number of ways to beat the record of the single race obtained by ignoring the spaces
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    // Create a single parser for all the lines
+    let mut parser = input.lines();
+
+    // Read time and distance, by folding the digits after the descriptors together into numbers
+    let (time, distance) = info_span!("parse").in_scope(|| Ok((
+        parse_number(0, parser.next(), "Time:")?,
+        parse_number(1, parser.next(), "Distance:")?,
+    )))?;
+
+    // Then find the range of integer solutions
+    Ok(info_span!("solve").in_scope(|| find_integer_solution_range(time, distance)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(71503));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(32583852));
+    }
+
+    #[test]
+    fn record_too_large() {
+        let err = solve("Time: 7 15 30\nDistance: 9999999999 9999999999\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 10: expected a number that fits in u64, found ' '");
+    }
+}
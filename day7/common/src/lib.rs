@@ -0,0 +1,84 @@
+//! Code shared by both parts of day 7 of Advent of Code 2023.
+
+/// Names of the types of hands by their score
+pub const TYPES: [&str; 7] = ["high card", "one pair", "two pair", "three of a kind", "full house", "four of a kind", "five of a kind"];
+
+// Function that finds the highest and second highest cards in a hand
+fn find_highest_and_second_highest(card_counts: &[usize]) -> (usize, usize) {
+    let mut highest = usize::MIN;
+    let mut second_highest = usize::MIN;
+
+    for count in card_counts {
+        if *count > highest {
+            second_highest = highest;
+            highest = *count;
+        } else if *count > second_highest {
+            second_highest = *count;
+        }
+    }
+
+    (highest, second_highest)
+}
+
+/// Score a hand of cards based on its type, from the number of cards of every kind and the number of jokers,
+/// which count as the kind there are most cards of
+pub fn score_type(card_counts: &[usize], jokers: usize) -> usize {
+    // Get the highest and second highest card counts
+    let (highest, second_highest) = find_highest_and_second_highest(card_counts);
+
+    // Based on the highest + jokers and second highest card counts assign a score
+    match jokers + highest {
+        // (0) High card        (highest count is 1)
+        1 => 0,
+        // (1) One pair         (highest count is 2 and second highest count is 1)
+        // (2) Two pair         (highest count is 2 and second highest count is 2)
+        2 => second_highest,
+        // (3) Three of a kind  (highest count is 3 and second highest count is 1)
+        // (4) Full house       (highest count is 3 and second highest count is 2)
+        3 => second_highest + 2,
+        // (5) Four of a kind   (highest count is 4)
+        4 => 5,
+        // (6) Five of a kind   (highest count is 5)
+        5 => 6,
+        _ => panic!("Invalid card counts"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // Type of a hand from the number of cards of every kind, by the counts of the kinds that are in it from most to least
+    fn hand_type(card_counts: &[usize]) -> usize {
+        let mut counts: Vec<usize> = card_counts.iter().copied().filter(|&count| count > 0).collect();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        match counts[..] {
+            [5] => 6,
+            [4, 1] => 5,
+            [3, 2] => 4,
+            [3, 1, 1] => 3,
+            [2, 2, 1] => 2,
+            [2, 1, 1, 1] => 1,
+            _ => 0,
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn highest_counts_are_the_largest_two(card_counts in prop::collection::vec(0_usize..10, 0..20)) {
+            let mut sorted = card_counts.clone();
+            sorted.sort_unstable_by(|a, b| b.cmp(a));
+            let expected = (sorted.first().copied().unwrap_or(0), sorted.get(1).copied().unwrap_or(0));
+            prop_assert_eq!(find_highest_and_second_highest(&card_counts), expected);
+        }
+
+        #[test]
+        fn score_is_the_type_of_the_hand(hand in prop::array::uniform5(0_usize..13)) {
+            let mut card_counts = vec![0; 13];
+            hand.iter().for_each(|&card| card_counts[card] += 1);
+            prop_assert_eq!(score_type(&card_counts, 0), hand_type(&card_counts));
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use std::io::{self, BufRead};
+
+use aoc_core::error::ParseError;
+use aoc_core::executor::Executor;
+use aoc_core::explain::Explanation;
+use aoc_core::parse::Cursor;
+use aoc_core::solver::LineSolver;
+use aoc_core::total::{FromValue, Total};
+use day7_common::{score_type, TYPES};
+
+use tracing::info_span;
+
+// Function that parses a card to its value
+fn parse_card(c: char) -> Option<usize> {
+    Some(match c {
+        'A' => 12,
+        'K' => 11,
+        'Q' => 10,
+        'J' => 9,
+        'T' => 8,
+        '9' => 7,
+        '8' => 6,
+        '7' => 5,
+        '6' => 4,
+        '5' => 3,
+        '4' => 2,
+        '3' => 1,
+        '2' => 0,
+        _ => return None,
+    })
+}
+
+// Function that scores a hand of card based on type
+fn score_hand(card_counts: Vec<usize>) -> usize {
+    score_type(&card_counts, 0)
+}
+
+// Type of a hand, its cards and its bid, which sort hands by their strength
+type Hand = (usize, [usize; 5], u64);
+
+// Function that processes each line
+fn process_line(line: &str) -> Result<Hand, ParseError> {
+    let mut cursor = Cursor::new(line);
+
+    // Parse the first 5 characters to a hand
+    let mut parsed_hand = [0; 5];
+    for card in &mut parsed_hand {
+        let offset = cursor.offset();
+        *card = cursor.next_char()
+            .and_then(parse_card)
+            .ok_or_else(|| cursor.error_at(offset, "a card"))?;
+    }
+    if !cursor.rest().starts_with(char::is_whitespace) {
+        return Err(cursor.error("a space after the 5 cards of the hand"));
+    }
+
+    // Count the number of matching cards of each kind
+    let mut card_counts = vec![0; 13];
+    parsed_hand.iter().for_each(|card| card_counts[*card] += 1);
+
+    // Score the hand based on the card counts
+    let score = score_hand(card_counts);
+
+    // Get the bid amount
+    let bid = cursor.number()?;
+    cursor.end()?;
+
+    Ok((score, parsed_hand, bid))
+}
+
+// Winnings are multiplied in 128 bits, where the rank times the bid always fits, and summed in 64 bits, or in 128 bits
+// with the `checked` feature, and checked for overflow
+type Winning = u128;
+
+#[cfg(not(feature = "checked"))]
+type Sum = aoc_core::total::Checked<u64>;
+#[cfg(feature = "checked")]
+type Sum = aoc_core::total::Checked<u128>;
+
+/// Type of the answer, which never overflows with the `checked` feature
+pub type Answer = <Sum as Total>::Answer;
+
+/// Ranks all hands by their type and cards, and sums their bids multiplied by their rank
+pub struct Winnings;
+
+impl LineSolver for Winnings {
+    type Accumulator = Vec<Hand>;
+    type Answer = Answer;
+
+    fn identity(&self) -> Vec<Hand> {
+        Vec::new()
+    }
+
+    fn map(&self, _: usize, line: &str) -> Result<Vec<Hand>, ParseError> {
+        Ok(vec![process_line(line)?])
+    }
+
+    fn combine(&self, mut a: Vec<Hand>, b: Vec<Hand>) -> Vec<Hand> {
+        a.extend(b);
+        a
+    }
+
+    fn finish(&self, mut hands: Vec<Hand>) -> Result<Answer, ParseError> {
+        // Sort the hands
+        // primary key:     type of hand
+        // secondary key:   cards in hand
+        // tertiary key:    bid
+        hands.sort_unstable();
+
+        // Sum the rank times bid for each hand
+        Sum::sum(hands.iter()
+            .enumerate()
+            .map(|(i, &(_, _, bid))| (i + 1) as Winning * bid as Winning))
+            .answer()
+    }
+}
+
+/// The cards, type, rank and winnings of every hand in the input
+pub fn explain(input: &str) -> Result<Vec<Explanation>, ParseError> {
+    let lines = aoc_core::executor::lines(input);
+    let hands = lines.iter()
+        .enumerate()
+        .map(|(index, line)| process_line(line).map_err(|err| err.at_line(index)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Rank the hands the same way as the solver sorts them, keeping them in the order of their lines
+    let mut order: Vec<usize> = (0..hands.len()).collect();
+    order.sort_unstable_by_key(|&index| hands[index]);
+    let mut ranks = vec![0; hands.len()];
+    order.iter().enumerate().for_each(|(i, &index)| ranks[index] = i + 1);
+
+    Ok(lines.iter()
+        .zip(hands)
+        .zip(ranks)
+        .map(|((line, (score, _, bid)), rank)| Explanation::new()
+            .text("hand", &line[..5])
+            .text("type", TYPES[score])
+            .number("rank", rank)
+            .number("winnings", rank as Winning * bid as Winning))
+        .collect())
+}
+
+/// The executor the day runs with by default
+pub fn executor() -> Executor {
+    Executor::default()
+}
+
+/// Sum the winnings of all hands, i.e. their bid multiplied by their rank
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    solve_with(input, &executor())
+}
+
+/// Sum the winnings of all hands, processing the lines with the given executor
+pub fn solve_with(input: &str, executor: &Executor) -> Result<Answer, ParseError> {
+    // Parse the lines to hands, then rank them and sum their winnings
+    info_span!("solve").in_scope(|| executor.solve(&Winnings, input))
+}
+
+/// Sum the winnings of all hands, processing the lines with the given executor as they are read from the reader
+pub fn solve_reader(reader: impl BufRead + Send, executor: &Executor) -> io::Result<Answer> {
+    info_span!("solve").in_scope(|| executor.solve_reader(&Winnings, reader))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(6440));
+    }
+
+    // Count the cards of each kind in a hand
+    fn count_cards(hand: &str) -> Vec<usize> {
+        let mut card_counts = vec![0; 13];
+        hand.chars().map(|c| parse_card(c).unwrap()).for_each(|card| card_counts[card] += 1);
+        card_counts
+    }
+
+    #[test]
+    fn score_hand_types() {
+        assert_eq!(score_hand(count_cards("23456")), 0); // high card
+        assert_eq!(score_hand(count_cards("32T3K")), 1); // one pair
+        assert_eq!(score_hand(count_cards("KTJJT")), 2); // two pair
+        assert_eq!(score_hand(count_cards("T55J5")), 3); // three of a kind
+        assert_eq!(score_hand(count_cards("AAAKK")), 4); // full house
+        assert_eq!(score_hand(count_cards("QQQQ2")), 5); // four of a kind
+        assert_eq!(score_hand(count_cards("AAAAA")), 6); // five of a kind
+    }
+
+    #[test]
+    fn explain_example() {
+        let explanations = explain(EXAMPLE).unwrap();
+        assert_eq!(explanations[0], Explanation::new().text("hand", "32T3K").text("type", "one pair").number("rank", 1).number("winnings", 765));
+        assert_eq!(explanations[4], Explanation::new().text("hand", "QQQJA").text("type", "three of a kind").number("rank", 5).number("winnings", 2415));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(248569531));
+    }
+
+    #[test]
+    fn malformed_hand() {
+        assert_eq!(solve("32T3K 765\n32T3X 684\n"), Err(ParseError::new("32T3X 684", 4, "a card").at_line(1)));
+    }
+
+    #[test]
+    fn winnings_beyond_64_bits() {
+        let input = "32T3K 18446744073709551615\nKK677 18446744073709551615\n";
+        #[cfg(not(feature = "checked"))]
+        assert_eq!(solve(input), Err(ParseError::overflow("a total that fits in u64")));
+        #[cfg(feature = "checked")]
+        assert_eq!(solve(input), Ok(3 * u128::from(u64::MAX)));
+    }
+}
@@ -0,0 +1,180 @@
+//! Code shared by both parts of day 8 of Advent of Code 2023.
+
+use aoc_core::error::ParseError;
+use aoc_core::executor::Executor;
+use aoc_core::parse::Cursor;
+
+/// Nodes are named by 3 digits in base 36
+pub const NODE_RADIX: u32 = 36;
+/// Number of possible node names
+pub const NODE_COUNT: usize = 46656; //NODE_RADIX.pow(3)
+
+/// Parse a sequence of digits and capital letters as a base 36 number like 001 to 1 and 010 to 36, if it fits
+pub fn sequence_to_number(sequence: &str) -> Option<usize> {
+    sequence.chars()
+        .map(|c| if c.is_ascii_lowercase() { None } else { c.to_digit(NODE_RADIX) })
+        .try_fold(0_usize, |output, digit| output.checked_mul(NODE_RADIX as usize)?.checked_add(digit? as usize))
+}
+
+// Read a node name of 3 capital letters or digits after skipping whitespace, together with the byte offset it starts at
+fn parse_node(cursor: &mut Cursor) -> Result<(usize, usize), ParseError> {
+    cursor.skip_whitespace();
+    let mut name = cursor.clone();
+    let node = name.take(3)
+        .and_then(sequence_to_number)
+        .ok_or_else(|| cursor.error("a node name of 3 capital letters or digits"))?;
+
+    let offset = cursor.offset();
+    *cursor = name;
+    Ok((node, offset))
+}
+
+// Function that processes each line, returning the name of the node, its children and the offsets of the name and the children in the line
+fn process_line(line: &str) -> Result<(usize, [usize; 2], [usize; 3]), ParseError> {
+    let mut cursor = Cursor::new(line);
+    let (name, name_offset) = parse_node(&mut cursor)?;
+    cursor.tag("= (")?;
+    let (left, left_offset) = parse_node(&mut cursor)?;
+    cursor.tag(",")?;
+    let (right, right_offset) = parse_node(&mut cursor)?;
+    cursor.tag(")")?;
+    cursor.end()?;
+
+    Ok((name, [left, right], [name_offset, left_offset, right_offset]))
+}
+
+// Maps L to 0 and R to 1
+fn direction_to_binary(direction: char) -> Option<usize> {
+    match direction {
+        'L' => Some(0),
+        'R' => Some(1),
+        _ => None,
+    }
+}
+
+/// Parse the first line to the steps, 0 for L and 1 for R, failing if there are none
+pub fn parse_steps(lines: &[&str]) -> Result<Vec<usize>, ParseError> {
+    let line = lines.first().ok_or_else(|| ParseError::end_of_input(0, "steps of L or R"))?;
+    let steps: Vec<usize> = line.char_indices()
+        .map(|(offset, direction)| direction_to_binary(direction).ok_or_else(|| ParseError::new(line, offset, "L or R").at_line(0)))
+        .collect::<Result<_, _>>()?;
+
+    if steps.is_empty() {
+        return Err(ParseError::new(line, 0, "steps of L or R").at_line(0));
+    }
+    Ok(steps)
+}
+
+/// Parse the nodes following the steps and an empty line with the executor to the network, holding the left and right
+/// children of every node name, together with the names of the nodes in the network
+pub fn parse_network(lines: &[&str], executor: &Executor) -> Result<(Vec<[usize; 2]>, Vec<usize>), ParseError> {
+    // The steps and the nodes are separated by an empty line
+    if let Some(line) = lines.get(1).filter(|line| !line.is_empty()) {
+        return Err(ParseError::new(line, 0, "an empty line").at_line(1));
+    }
+
+    let nodes = executor.map_collect(lines.get(2..).unwrap_or_default(), |i, line| process_line(line).map_err(|err| err.at_line(i + 2)))
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut network = vec![[0; 2]; NODE_COUNT];
+    let mut defined = vec![false; NODE_COUNT];
+    for (i, &(name, directions, offsets)) in nodes.iter().enumerate() {
+        // A node is defined only once, so the error points at its second definition
+        if defined[name] {
+            return Err(ParseError::new(lines[i + 2], offsets[0], "a node that is not defined yet").at_line(i + 2));
+        }
+        // Store the directions in the network
+        network[name] = directions;
+        defined[name] = true;
+    }
+
+    // Every child has to be defined itself, or it would lead to node 000 in the network
+    for (i, &(_, directions, offsets)) in nodes.iter().enumerate() {
+        if let Some(side) = (0..2).find(|&side| !defined[directions[side]]) {
+            return Err(ParseError::new(lines[i + 2], offsets[side + 1], "a defined node").at_line(i + 2));
+        }
+    }
+    Ok((network, nodes.into_iter().map(|(name, _, _)| name).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn separated_by_an_empty_line() {
+        let lines = ["L", "xyz", "AAA = (ZZZ, ZZZ)", "ZZZ = (ZZZ, ZZZ)"];
+        let err = parse_network(&lines, &Executor::sequential()).unwrap_err();
+        assert_eq!(err.diagnostic(), "\
+line 2, column 1: expected an empty line, found 'x'
+  |
+2 | xyz
+  | ^");
+        assert!(parse_network(&["L", ""], &Executor::sequential()).is_ok());
+    }
+
+    #[test]
+    fn children_are_defined() {
+        let lines = ["L", "", "AAA = (ZZZ, ZZZ)", "ZZZ = (ZZZ, BBB)"];
+        let err = parse_network(&lines, &Executor::sequential()).unwrap_err();
+        assert_eq!(err.diagnostic(), "\
+line 4, column 13: expected a defined node, found 'B'
+  |
+4 | ZZZ = (ZZZ, BBB)
+  |             ^");
+    }
+
+    #[test]
+    fn nodes_are_defined_once() {
+        let lines = ["L", "", "AAA = (ZZZ, ZZZ)", "ZZZ = (ZZZ, ZZZ)", "AAA = (AAA, AAA)"];
+        let err = parse_network(&lines, &Executor::sequential()).unwrap_err();
+        assert_eq!(err.diagnostic(), "\
+line 5, column 1: expected a node that is not defined yet, found 'A'
+  |
+5 | AAA = (AAA, AAA)
+  | ^");
+    }
+
+    #[test]
+    fn names_are_capitals() {
+        assert_eq!(sequence_to_number("zzz"), None);
+        let err = parse_network(&["L", "", "zzz = (zzz, zzz)"], &Executor::sequential()).unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 1: expected a node name of 3 capital letters or digits, found 'z'");
+    }
+
+    #[test]
+    fn node_count() {
+        assert_eq!(sequence_to_number("ZZZ"), Some(NODE_COUNT - 1));
+    }
+
+    proptest! {
+        #[test]
+        fn names_are_base_36(name in "[0-9A-Z]{3}") {
+            let number = sequence_to_number(&name).unwrap();
+            prop_assert!(number < NODE_COUNT);
+            let digits = name.chars().map(|c| "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ".find(c).unwrap());
+            prop_assert_eq!(number, digits.fold(0, |number, digit| number * 36 + digit));
+        }
+
+        #[test]
+        fn names_keep_their_order(a in "[0-9A-Z]{3}", b in "[0-9A-Z]{3}") {
+            prop_assert_eq!(a.cmp(&b), sequence_to_number(&a).cmp(&sequence_to_number(&b)));
+        }
+
+        #[test]
+        fn other_characters_are_rejected(name in "[0-9A-Z]{0,2}[^0-9A-Z][0-9A-Z]{0,2}") {
+            prop_assert_eq!(sequence_to_number(&name), None);
+        }
+
+        #[test]
+        fn long_names_do_not_overflow(name in "[0-9A-Z]{1,20}") {
+            // Names fit in 64 bits up to 3W5E11264SGSF, the largest 64 bit number in base 36
+            let digits = name.trim_start_matches('0');
+            let fits = digits.len() < 13 || (digits.len() == 13 && digits <= "3W5E11264SGSF");
+            prop_assert_eq!(sequence_to_number(&name).is_some(), fits);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use aoc_core::error::ParseError;
+use aoc_core::executor::{self, Executor};
+use day8_common::{parse_network, parse_steps};
+
+use tracing::info_span;
+
+const START_NODE: usize = 13330; //sequence_to_number("AAA");
+const END_NODE: usize = 46655; //sequence_to_number("ZZZ");
+
+/// The executor the day runs with by default
+pub fn executor() -> Executor {
+    Executor::default()
+}
+
+/// Count the steps required to get from AAA to ZZZ
+pub fn solve(input: &str) -> Result<u64, ParseError> {
+    solve_with(input, &executor())
+}
+
+/// Count the steps required to get from AAA to ZZZ, parsing the network with the given executor
+pub fn solve_with(input: &str, executor: &Executor) -> Result<u64, ParseError> {
+    // Split the input into lines
+    let parse = info_span!("parse").entered();
+    let lines = executor::lines(input);
+
+    // Get the stepping function and parse it to 0 if L and 1 if R
+    let steps = parse_steps(&lines)?;
+
+    // Get the network nodes and parse them to an array with left and right children
+    let (network, nodes) = parse_network(&lines, executor)?;
+
+    // The START node has to be in the network
+    if !nodes.contains(&START_NODE) {
+        return Err(ParseError::end_of_input(lines.len(), "a node named AAA"));
+    }
+
+    drop(parse);
+    let _solve = info_span!("solve").entered();
+
+    // Traverse the network according to steps until we reach the END node
+    // Every node we can visit is a node in the network, so after visiting every node at every position in the steps
+    // we are walking in circles that never reach the END node
+    let states = nodes.len() * steps.len();
+    let mut current_node = START_NODE;
+    for (step_count, &direction) in (1..).zip(steps.iter().cycle().take(states)) {
+        current_node = network[current_node][direction];
+        // If we reach the exit return the number of steps
+        if current_node == END_NODE {
+            return Ok(step_count);
+        }
+    }
+
+    Err(ParseError::end_of_input(lines.len(), "a path from AAA to ZZZ"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use day8_common::{sequence_to_number, NODE_COUNT};
+
+    const EXAMPLE_1: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE_2: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    #[test]
+    fn example_1() {
+        assert_eq!(solve(EXAMPLE_1), Ok(2));
+    }
+
+    #[test]
+    fn example_2() {
+        assert_eq!(solve(EXAMPLE_2), Ok(6));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(18157));
+    }
+
+    #[test]
+    fn malformed_node() {
+        let line = "AAA = (BBB, ZZ)";
+        assert_eq!(solve(&format!("LR\n\n{}\n", line)), Err(ParseError::new(line, 12, "a node name of 3 capital letters or digits").at_line(2)));
+    }
+
+    #[test]
+    fn unreachable_end() {
+        assert_eq!(solve("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n"), Err(ParseError::end_of_input(5, "a path from AAA to ZZZ")));
+    }
+
+    #[test]
+    fn node_constants() {
+        assert_eq!(sequence_to_number("AAA"), Some(START_NODE));
+        assert_eq!(sequence_to_number("ZZZ"), Some(END_NODE));
+        assert_eq!(sequence_to_number("ZZZ"), Some(NODE_COUNT - 1));
+    }
+}
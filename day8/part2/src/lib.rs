@@ -0,0 +1,295 @@
+use aoc_core::error::ParseError;
+use aoc_core::executor::{self, Executor};
+use day8_common::{parse_network, parse_steps, NODE_COUNT, NODE_RADIX};
+
+use tracing::info_span;
+
+const A_DIGIT: usize = 10; //'A'.to_digit(NODE_RADIX)
+const Z_DIGIT: usize = 35; //'Z'.to_digit(NODE_RADIX)
+
+/// Number of steps, which is the least common multiple of the steps of every path and a big integer with the `checked` feature
+#[cfg(not(feature = "checked"))]
+pub type Steps = u64;
+#[cfg(feature = "checked")]
+pub type Steps = num::BigUint;
+
+// Convert a number of steps to the answer, failing if it does not fit
+#[cfg(not(feature = "checked"))]
+fn to_steps(steps: u128) -> Result<Steps, ParseError> {
+    Steps::try_from(steps).map_err(|_| ParseError::overflow("a number of steps that fits in u64"))
+}
+#[cfg(feature = "checked")]
+fn to_steps(steps: u128) -> Result<Steps, ParseError> {
+    Ok(Steps::from(steps))
+}
+
+// Least common multiple of two numbers of steps, failing if it does not fit
+#[cfg(not(feature = "checked"))]
+fn lcm(a: Steps, b: Steps) -> Result<Steps, ParseError> {
+    (a / num::integer::gcd(a, b)).checked_mul(b).ok_or_else(|| ParseError::overflow("a number of steps that fits in u64"))
+}
+#[cfg(feature = "checked")]
+fn lcm(a: Steps, b: Steps) -> Result<Steps, ParseError> {
+    Ok(num::integer::lcm(a, b))
+}
+
+// Most combinations of the times in the cycles of the paths that are combined, beyond which there are too many to try
+const MAX_COMBINATIONS: usize = 1 << 16;
+
+// The times at which a path is at a node ending with Z, which repeat every period after the path repeats itself
+struct Path {
+    // Time after which the path repeats itself
+    start: u128,
+    period: u128,
+    // Times up to and including the start, in order
+    tail: Vec<u128>,
+    // Times after the start up to and including the start plus the period, in order
+    cycle: Vec<u128>,
+}
+
+impl Path {
+    // Whether the path is at a node ending with Z at the time
+    fn at_end(&self, time: u128) -> bool {
+        if time <= self.start {
+            self.tail.binary_search(&time).is_ok()
+        } else {
+            self.cycle.binary_search(&(self.start + 1 + (time - self.start - 1) % self.period)).is_ok()
+        }
+    }
+
+    // The number of steps if the path is at a node ending with Z at exactly its multiples, which the least common multiple combines
+    fn multiples(&self) -> Option<u128> {
+        (self.tail.is_empty() && self.cycle == [self.period]).then_some(self.period)
+    }
+}
+
+// Walk the path from a node until it repeats itself, which it does once it is at a node it was at before at the start of the steps
+fn walk(network: &[[usize; 2]], steps: &[usize], node: usize) -> Path {
+    let mut rounds = vec![None; NODE_COUNT];
+    let mut times = Vec::new();
+    let mut node = node;
+    let mut time = 0;
+    for round in 0.. {
+        if let Some(first) = rounds[node] {
+            let start = (first * steps.len()) as u128;
+            let split = times.partition_point(|&time| time <= start);
+            let cycle = times.split_off(split);
+            return Path { start, period: time - start, tail: times, cycle };
+        }
+        rounds[node] = Some(round);
+
+        for &direction in steps {
+            node = network[node][direction];
+            time += 1;
+            if node % NODE_RADIX as usize == Z_DIGIT {
+                times.push(time);
+            }
+        }
+    }
+    unreachable!("a path visits every node at most once at the start of the steps")
+}
+
+// Combine the congruences time = a modulo m and time = b modulo n into one, if both can hold
+fn combine((a, m): (u128, u128), (b, n): (u128, u128)) -> Result<Option<(u128, u128)>, ParseError> {
+    let g = gcd(m, n);
+    let difference = (b % n + n - a % n) % n;
+    if difference % g != 0 {
+        return Ok(None);
+    }
+
+    // Solve a + m * k = b modulo n for k, where the periods of the paths keep n small enough to multiply without overflow
+    let (m_g, n_g) = (m / g, n / g);
+    let k = (difference / g) % n_g * inverse(m_g % n_g, n_g) % n_g;
+    let overflow = || ParseError::overflow("a number of steps that fits in u128");
+    let modulus = m_g.checked_mul(n).ok_or_else(overflow)?;
+    let time = m.checked_mul(k).and_then(|mk| mk.checked_add(a)).ok_or_else(overflow)?;
+    Ok(Some((time % modulus, modulus)))
+}
+
+// Greatest common divisor of two numbers
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+// Multiplicative inverse of a number modulo a coprime modulus, found with the extended euclidean algorithm
+fn inverse(a: u128, modulus: u128) -> u128 {
+    let (mut r, mut new_r) = (modulus as i128, a as i128);
+    let (mut t, mut new_t) = (0_i128, 1_i128);
+    while new_r != 0 {
+        let quotient = r / new_r;
+        (t, new_t) = (new_t, t - quotient * new_t);
+        (r, new_r) = (new_r, r - quotient * new_r);
+    }
+    t.rem_euclid(modulus as i128) as u128
+}
+
+// The first time at which every path is at a node ending with Z, if there is one
+fn first_common_end(paths: &[Path]) -> Result<Option<u128>, ParseError> {
+    // Before the last path repeats itself, only the times of its tail can be common
+    let last = paths.iter().max_by_key(|path| path.start).expect("there is at least one path");
+    if let Some(&time) = last.tail.iter().find(|&&time| paths.iter().all(|path| path.at_end(time))) {
+        return Ok(Some(time));
+    }
+
+    // After that every path repeats itself, so combine every time in the cycle of every path into the congruences that hold
+    let mut congruences = vec![(0, 1)];
+    for path in paths {
+        let mut combined = Vec::new();
+        for &congruence in &congruences {
+            for &time in &path.cycle {
+                combined.extend(combine(congruence, (time % path.period, path.period))?);
+            }
+        }
+        combined.sort_unstable();
+        combined.dedup();
+        if combined.len() > MAX_COMBINATIONS {
+            return Err(ParseError::limit(format!("at most {} combinations of the times the paths are at a node ending with Z", MAX_COMBINATIONS)));
+        }
+        congruences = combined;
+    }
+
+    // Take the first time after the start of the last cycle for every congruence
+    let after = last.start + 1;
+    congruences.iter()
+        .map(|&(time, modulus)| {
+            let offset = after % modulus;
+            after.checked_add(if time >= offset { time - offset } else { modulus - (offset - time) })
+        })
+        .try_fold(None, |first: Option<u128>, time| {
+            let time = time.ok_or_else(|| ParseError::overflow("a number of steps that fits in u128"))?;
+            Ok(Some(first.map_or(time, |first| first.min(time))))
+        })
+}
+
+/// The executor the day runs with by default
+pub fn executor() -> Executor {
+    Executor::default()
+}
+
+/// Count the steps required until all paths starting at a node ending with A are at a node ending with Z simultaneously
+pub fn solve(input: &str) -> Result<Steps, ParseError> {
+    solve_with(input, &executor())
+}
+
+/// Count the steps required until all paths are at an end node simultaneously, processing the lines and paths with the given executor
+pub fn solve_with(input: &str, executor: &Executor) -> Result<Steps, ParseError> {
+    // Split the input into lines
+    let parse = info_span!("parse").entered();
+    let lines = executor::lines(input);
+
+    // Get the stepping function and parse it to 0 if L and 1 if R
+    let steps = parse_steps(&lines)?;
+
+    // Get the network nodes and parse them to an array with left and right children
+    // Then get our start nodes, which are the nodes ending in A
+    let (network, nodes) = parse_network(&lines, executor)?;
+    let start_nodes: Vec<usize> = nodes.into_iter()
+        .filter(|name| name % NODE_RADIX as usize == A_DIGIT)
+        .collect();
+
+    // There has to be at least one START node
+    if start_nodes.is_empty() {
+        return Err(ParseError::end_of_input(lines.len(), "a node ending with A"));
+    }
+
+    drop(parse);
+    let _solve = info_span!("solve").entered();
+
+    // Walk the path from every START node until it repeats itself, recording when it is at an END node
+    let paths = executor.map_collect(&start_nodes, |_, &node| walk(&network, &steps, node));
+    if paths.iter().any(|path| path.tail.is_empty() && path.cycle.is_empty()) {
+        return Err(ParseError::end_of_input(lines.len(), "a path from every node ending with A to a node ending with Z"));
+    }
+
+    // When every path is at an END node at exactly the multiples of its steps, the Lowest Common Multiple of these
+    // steps is the step count that reaches all END nodes
+    if let Some(multiples) = paths.iter().map(Path::multiples).collect::<Option<Vec<u128>>>() {
+        return multiples.into_iter()
+            .map(to_steps)
+            .try_fold(Steps::from(1_u8), |a, b| lcm(a, b?));
+    }
+
+    // Otherwise find the first time the paths are at END nodes together from when they do so
+    let time = first_common_end(&paths)?
+        .ok_or_else(|| ParseError::end_of_input(lines.len(), "paths that are at nodes ending with Z at the same time"))?;
+    to_steps(time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use day8_common::sequence_to_number;
+
+    const EXAMPLE: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(Steps::from(6_u64)));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(Steps::from(14299763833181_u64)));
+    }
+
+    #[test]
+    fn unreachable_end() {
+        assert_eq!(solve("L\n\n11A = (11B, 11B)\n11B = (11A, 11Z)\n11Z = (11Z, 11Z)\n"), Err(ParseError::end_of_input(5, "a path from every node ending with A to a node ending with Z")));
+    }
+
+    #[test]
+    fn offset_cycles() {
+        // The first path is at 11Z after 1, 4, 7, ... steps and the second at 22Z after 2, 4, 6, ... steps,
+        // so they are not together after the least common multiple of their first steps
+        let input = "L\n\n11A = (11Z, XXX)\n11Z = (11B, XXX)\n11B = (11C, XXX)\n11C = (11Z, XXX)\n22A = (22B, XXX)\n22B = (22Z, XXX)\n22Z = (22B, XXX)\nXXX = (XXX, XXX)\n";
+        assert_eq!(solve(input), Ok(Steps::from(4_u64)));
+    }
+
+    #[test]
+    fn never_together() {
+        // The first path is only at 11Z after an odd number of steps and the second only after an even number
+        let input = "L\n\n11A = (11Z, XXX)\n11Z = (11B, XXX)\n11B = (11Z, XXX)\n22A = (22B, XXX)\n22B = (22Z, XXX)\n22Z = (22B, XXX)\nXXX = (XXX, XXX)\n";
+        assert_eq!(solve(input), Err(ParseError::end_of_input(9, "paths that are at nodes ending with Z at the same time")));
+    }
+
+    #[test]
+    fn too_many_combinations() {
+        // Paths around rings of coprime lengths that are at a node ending with Z at every step but one,
+        // which a solution exists for but combine into more times than the search tries
+        let digits: Vec<char> = ('0'..='9').chain('A'..='Z').collect();
+        let mut input = String::from("L\n\n");
+        for (path, length) in [11, 13, 17, 19, 23].into_iter().enumerate() {
+            let node = |i: usize| format!("{}{}{}", path + 1, digits[i], if i == 0 { 'B' } else { 'Z' });
+            input += &format!("{}0A = ({}, {})\n", path + 1, node(0), node(0));
+            for i in 0..length {
+                input += &format!("{} = ({}, {})\n", node(i), node((i + 1) % length), node((i + 1) % length));
+            }
+        }
+        assert_eq!(solve(&input), Err(ParseError::limit(format!("at most {} combinations of the times the paths are at a node ending with Z", MAX_COMBINATIONS))));
+    }
+
+    #[test]
+    #[cfg(not(feature = "checked"))]
+    fn lcm_overflow() {
+        assert_eq!(lcm(6, 4), Ok(12));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), Err(ParseError::overflow("a number of steps that fits in u64")));
+    }
+
+    #[test]
+    fn node_constants() {
+        assert_eq!(sequence_to_number("A"), Some(A_DIGIT));
+        assert_eq!(sequence_to_number("Z"), Some(Z_DIGIT));
+        assert_eq!(sequence_to_number("ZZZ"), Some(NODE_COUNT - 1));
+    }
+}
@@ -0,0 +1,115 @@
+//! Code shared by both parts of day 9 of Advent of Code 2023.
+
+use aoc_core::error::ParseError;
+#[cfg(feature = "checked")]
+use num_integer::IterBinomial;
+
+/// Type of an extrapolated value, which is a big integer with the `checked` feature
+#[cfg(not(feature = "checked"))]
+pub type Value = i64;
+#[cfg(feature = "checked")]
+pub type Value = num::BigInt;
+
+/// Extrapolate the next value of the history from its rows of differences, failing with an overflow error if it does not fit
+/// in 64 bits, or if the differences do not fit in 128 bits before they reach a row of zeros
+#[cfg(not(feature = "checked"))]
+pub fn extrapolate(history: &[i64]) -> Result<Value, ParseError> {
+    // The next value is the sum of the last values of the history and of every row of differences above the row of zeros,
+    // where every row of differences replaces the row it is taken from
+    let overflow = || ParseError::overflow("differences of the history that fit in i128");
+    let mut row: Vec<i128> = history.iter().map(|&value| i128::from(value)).collect();
+    let mut next = 0_i128;
+    while row.iter().any(|&value| value != 0) {
+        next = next.checked_add(row[row.len() - 1]).ok_or_else(overflow)?;
+        for i in 0..row.len() - 1 {
+            row[i] = row[i + 1].checked_sub(row[i]).ok_or_else(overflow)?;
+        }
+        row.pop();
+    }
+    Value::try_from(next).map_err(|_| ParseError::overflow("an extrapolated value that fits in i64"))
+}
+
+/// Extrapolate the next value of the history with big integers, so the binomials and partial sums can get as large as they need
+#[cfg(feature = "checked")]
+pub fn extrapolate(history: &[i64]) -> Result<Value, ParseError> {
+    Ok(history.iter().rev()
+        .zip(IterBinomial::new(num::BigInt::from(history.len())).skip(1))
+        .enumerate()
+        .fold(num::BigInt::ZERO, |new_entry, (index, (&entry, binomial))| {
+            // Add the even indexed values to the total and subtract the odd indexed values from it
+            if index & 1 == 0 {
+                new_entry + binomial * entry
+            } else {
+                new_entry - binomial * entry
+            }
+        }))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn constant_history() {
+        assert_eq!(extrapolate(&[7; 5]), Ok(Value::from(7)));
+        assert_eq!(extrapolate(&[]), Ok(Value::from(0)));
+    }
+
+    #[test]
+    #[cfg(not(feature = "checked"))]
+    fn long_history() {
+        let history: Vec<i64> = (0..1_000_000).map(|x| 3 * x - 5).collect();
+        assert_eq!(extrapolate(&history), Ok(2_999_995));
+    }
+
+    #[test]
+    #[cfg(not(feature = "checked"))]
+    fn overflow() {
+        assert_eq!(extrapolate(&[i64::MAX - 1, i64::MAX]), Err(ParseError::overflow("an extrapolated value that fits in i64")));
+        assert_eq!(extrapolate(&[i64::MAX, i64::MIN, i64::MAX]), Err(ParseError::overflow("an extrapolated value that fits in i64")));
+        // The differences of values that alternate between the limits double with every row until they no longer fit
+        let history: Vec<i64> = (0..200).map(|x| if x % 2 == 0 { i64::MAX } else { i64::MIN }).collect();
+        assert_eq!(extrapolate(&history), Err(ParseError::overflow("differences of the history that fit in i128")));
+    }
+
+    // Value of the polynomial with the given leading differences at x, i.e. the sum of every difference times x choose its index
+    fn value(differences: &[i64], x: i128) -> i128 {
+        differences.iter()
+            .enumerate()
+            .map(|(k, &difference)| (0..k as i128).fold(1, |binomial, i| binomial * (x - i) / (i + 1)) * i128::from(difference))
+            .sum()
+    }
+
+    // Extrapolate the history of the values from 0 to n - 1, if the values and the expected next value fit
+    fn sample(n: usize, value: impl Fn(i128) -> i128) -> Option<(Value, i64)> {
+        let history: Vec<i64> = (0..n as i128).map(|x| i64::try_from(value(x)).ok()).collect::<Option<_>>()?;
+        let expected = i64::try_from(value(n as i128)).ok()?;
+        Some((extrapolate(&history).unwrap(), expected))
+    }
+
+    // Degree and leading differences of a polynomial and a number of points to sample it at, which is more than its degree.
+    // The degree stays low enough for the values and their differences to fit in 64 bits
+    fn polynomials() -> impl Strategy<Value = (usize, Vec<i64>)> {
+        (1_usize..=200).prop_flat_map(|n| (Just(n), prop::collection::vec(-1000_i64..=1000, 1..=n.min(8))))
+    }
+
+    proptest! {
+        #[test]
+        fn extrapolates_polynomials((n, differences) in polynomials()) {
+            // A polynomial of degree k sampled at n > k points has its value at n as the next value
+            let (answer, expected) = sample(n, |x| value(&differences, x)).unwrap();
+            prop_assert_eq!(answer, Value::from(expected));
+        }
+
+        #[test]
+        fn extrapolates_near_the_limits(n in 2_usize..=200, start in any::<i64>(), step in -1000_i64..=1000) {
+            // The values are close to the limits, while their differences and the answer still fit
+            let extrapolated = sample(n, |x| i128::from(start) + x * i128::from(step));
+            prop_assume!(extrapolated.is_some());
+            let (answer, expected) = extrapolated.unwrap();
+            prop_assert_eq!(answer, Value::from(expected));
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use std::io::{self, BufRead};
+
+use aoc_core::error::ParseError;
+use aoc_core::executor::Executor;
+use aoc_core::explain::{self, Explanation};
+use aoc_core::parse::Cursor;
+use aoc_core::solver::LineSolver;
+use aoc_core::total::{FromValue, Total};
+use day9_common::extrapolate;
+
+use tracing::info_span;
+
+/// Function that processes each line
+pub fn process_line(line: &str) -> Result<Answer, ParseError> {
+    // Split the line into an array of numbers
+    let history: Vec<i64> = Cursor::new(line).numbers()?;
+    extrapolate(&history)
+}
+
+/// The number of values and the extrapolated next value of every history in the input
+pub fn explain(input: &str) -> Result<Vec<Explanation>, ParseError> {
+    explain::lines(input, |line| {
+        let history: Vec<i64> = Cursor::new(line).numbers()?;
+        Ok(Explanation::new().number("values", history.len()).number("next", extrapolate(&history)?))
+    })
+}
+
+// Extrapolated values are summed in 64 bits and checked for overflow, or computed and summed as big integers with the
+// `checked` feature
+#[cfg(not(feature = "checked"))]
+type Sum = aoc_core::total::Checked<i64>;
+#[cfg(feature = "checked")]
+type Sum = num::BigInt;
+
+/// Type of the answer, which never overflows with the `checked` feature
+pub type Answer = <Sum as Total>::Answer;
+
+/// Sums the extrapolated next values of all histories
+pub struct Extrapolation;
+
+impl LineSolver for Extrapolation {
+    type Accumulator = Sum;
+    type Answer = Answer;
+
+    fn identity(&self) -> Sum {
+        Sum::zero()
+    }
+
+    fn map(&self, _: usize, line: &str) -> Result<Sum, ParseError> {
+        process_line(line).map(Sum::of)
+    }
+
+    fn combine(&self, a: Sum, b: Sum) -> Sum {
+        a.add(b)
+    }
+
+    fn finish(&self, sum: Sum) -> Result<Answer, ParseError> {
+        sum.answer()
+    }
+}
+
+/// The executor the day runs with by default
+pub fn executor() -> Executor {
+    Executor::default()
+}
+
+/// Sum the extrapolated next values of all histories
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    solve_with(input, &executor())
+}
+
+/// Sum the extrapolated next values of all histories, processing the lines with the given executor
+pub fn solve_with(input: &str, executor: &Executor) -> Result<Answer, ParseError> {
+    // Process the lines and sum the result
+    info_span!("solve").in_scope(|| executor.solve(&Extrapolation, input))
+}
+
+/// Sum the extrapolated next values of all histories, processing the lines with the given executor as they are read from the reader
+pub fn solve_reader(reader: impl BufRead + Send, executor: &Executor) -> io::Result<Answer> {
+    info_span!("solve").in_scope(|| executor.solve_reader(&Extrapolation, reader))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(solve(EXAMPLE), Ok(Answer::from(114_i64)));
+    }
+
+    #[test]
+    fn explain_example() {
+        assert_eq!(explain(EXAMPLE).unwrap()[2], Explanation::new().number("values", 6).number("next", 68));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(solve(include_str!("../../input")), Ok(Answer::from(1584748274_i64)));
+    }
+
+    #[test]
+    fn long_histories() {
+        // The binomials of histories of more than 66 values do not fit in 64 bits
+        let history: Vec<String> = (0..200).map(|value| value.to_string()).collect();
+        assert_eq!(process_line(&history.join(" ")), Ok(Answer::from(200_i64)));
+    }
+
+    #[test]
+    fn sum_beyond_64_bits() {
+        let history = vec![i64::MAX.to_string(); 3].join(" ");
+        let input = format!("{}\n{}\n", history, history);
+        #[cfg(not(feature = "checked"))]
+        assert_eq!(solve(&input), Err(ParseError::overflow("a total that fits in i64")));
+        #[cfg(feature = "checked")]
+        assert_eq!(solve(&input), Ok(num::BigInt::from(i64::MAX) * 2));
+    }
+
+    #[cfg(not(feature = "checked"))]
+    #[test]
+    fn beyond_64_bits() {
+        // The extrapolated value of the largest numbers does not fit in 64 bits, which is an overflow rather than a wrapped value
+        let err = solve("9223372036854775806 9223372036854775807\n").unwrap_err();
+        assert_eq!(err.to_string(), "the answer overflowed, expected an extrapolated value that fits in i64");
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn beyond_64_bits() {
+        // The extrapolated value of the largest numbers does not fit in 64 bits, nor do the binomials of long histories
+        assert_eq!(process_line(&format!("{} {}", i64::MAX - 1, i64::MAX)), Ok(num::BigInt::from(i64::MAX) + 1));
+        let history = vec![i64::MAX.to_string(); 200];
+        assert_eq!(process_line(&history.join(" ")), Ok(num::BigInt::from(i64::MAX)));
+    }
+}
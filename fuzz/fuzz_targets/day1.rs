@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Both parts have to either answer or report where the input is malformed, the fuzzer reports panics and hangs
+fuzz_target!(|data: &[u8]| {
+    let input = String::from_utf8_lossy(data);
+    let _ = day1_part1::solve(&input);
+    let _ = day1_part2::solve(&input);
+});